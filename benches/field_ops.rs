@@ -1,12 +1,12 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use num_bigint::BigUint;
-use zerosync::arithmetic::{
-    field::Fp,
-    simd::{field_mul_avx2, field_add_avx2, has_avx2},
-};
+use zerosync::arithmetic::field::Fp;
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+use zerosync::arithmetic::simd::{field_mul_avx2, field_add_avx2, has_avx2};
 use rand::Rng;
 use zerosync::arithmetic::traits::Field;
-use num_traits::{Zero, One};
+use zerosync::polynomial::Polynomial;
+use num_traits::Num;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -66,6 +66,7 @@ fn field_arithmetic_benchmark(c: &mut Criterion) {
     }
 
     // SIMD arithmetic benchmarks
+    #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
     if has_avx2() {
         let mut rng = rand::thread_rng();
         let a: [u64; 4] = std::array::from_fn(|_| rng.gen());
@@ -99,21 +100,11 @@ fn field_arithmetic_benchmark(c: &mut Criterion) {
             .collect();
 
         group.bench_function("batch/sum", |bencher| {
-            bencher.iter(|| {
-                elements.iter().fold(
-                    Fp::new(BigUint::from(0u32), modulus.clone()),
-                    |acc, x| acc + x.clone()
-                )
-            })
+            bencher.iter(|| elements.iter().sum::<Fp>())
         });
 
         group.bench_function("batch/product", |bencher| {
-            bencher.iter(|| {
-                elements.iter().fold(
-                    Fp::new(BigUint::from(1u32), modulus.clone()),
-                    |acc, x| acc * x.clone()
-                )
-            })
+            bencher.iter(|| elements.iter().product::<Fp>())
         });
     }
 
@@ -136,8 +127,8 @@ fn bench_field_add(c: &mut Criterion) {
     
     // Benchmark addition with different input sizes
     let test_cases = vec![
-        ("small_values", (10u32, 20u32)), 
-        ("medium_values", (u32::MAX, u32::MAX)),
+        ("small_values", (10u64, 20u64)), 
+        ("medium_values", (u32::MAX as u64, u32::MAX as u64)),
         ("large_values", (12345678u64, 87654321u64)),
     ];
     
@@ -172,8 +163,8 @@ fn bench_field_mul(c: &mut Criterion) {
     
     // Benchmark multiplication with different input sizes
     let test_cases = vec![
-        ("small_values", (10u32, 20u32)), 
-        ("medium_values", (u32::MAX, u32::MAX)),
+        ("small_values", (10u64, 20u64)), 
+        ("medium_values", (u32::MAX as u64, u32::MAX as u64)),
         ("large_values", (12345678u64, 87654321u64)),
     ];
     
@@ -190,8 +181,8 @@ fn bench_field_mul(c: &mut Criterion) {
     
     // Benchmark squaring (should be faster than general multiplication)
     let values = vec![
-        ("small", 42u32),
-        ("medium", u32::MAX),
+        ("small", 42u64),
+        ("medium", u32::MAX as u64),
         ("large", 12345678901u64),
     ];
     
@@ -216,10 +207,10 @@ fn bench_field_inverse(c: &mut Criterion) {
     
     // Benchmark inversion with different input sizes
     let values = vec![
-        ("small", 42u32),
-        ("medium", u32::MAX),
+        ("small", 42u64),
+        ("medium", u32::MAX as u64),
         ("large", 12345678901u64),
-        ("random", 0u32), // placeholder for random
+        ("random", 0u64), // placeholder for random
     ];
     
     for (name, val) in values {
@@ -268,6 +259,29 @@ fn bench_field_exponentiation(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_square_root(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Square Root");
+    
+    // -1 is a non-residue since p ≡ 3 (mod 4)
+    let residue = random_fp().square();
+    let non_residue = -residue.clone();
+    assert!(non_residue.sqrt().is_none());
+    
+    group.bench_function("sqrt/residue", |bench| {
+        bench.iter(|| black_box(residue.sqrt()))
+    });
+    
+    group.bench_function("sqrt/non_residue", |bench| {
+        bench.iter(|| black_box(non_residue.sqrt()))
+    });
+    
+    group.bench_function("legendre", |bench| {
+        bench.iter(|| black_box(residue.legendre()))
+    });
+    
+    group.finish();
+}
+
 fn bench_montgomery_operations(c: &mut Criterion) {
     let modulus = BigUint::from_str(BN254_MODULUS).unwrap();
     let mut group = c.benchmark_group("Montgomery Form");
@@ -275,8 +289,8 @@ fn bench_montgomery_operations(c: &mut Criterion) {
     
     // Benchmark Montgomery form conversion
     let values = vec![
-        ("small", 42u32),
-        ("medium", u32::MAX),
+        ("small", 42u64),
+        ("medium", u32::MAX as u64),
         ("large", 12345678901u64),
     ];
     
@@ -298,13 +312,25 @@ fn bench_montgomery_operations(c: &mut Criterion) {
                 black_box(a.from_montgomery())
             })
         });
+        
+        // Limb and byte outputs skip the intermediate BigUint
+        group.bench_with_input(BenchmarkId::new("to_canonical_limbs", name), &a, |bench, a| {
+            bench.iter(|| {
+                black_box(a.to_canonical_limbs())
+            })
+        });
+        
+        group.bench_with_input(BenchmarkId::new("to_bytes_be", name), &a, |bench, a| {
+            bench.iter(|| {
+                black_box(a.to_bytes_be())
+            })
+        });
     }
     
     group.finish();
 }
 
 fn bench_lazy_reduction(c: &mut Criterion) {
-    let modulus = BigUint::from_str(BN254_MODULUS).unwrap();
     let mut group = c.benchmark_group("Lazy Reduction");
     group.measurement_time(Duration::from_secs(5));
     
@@ -334,9 +360,32 @@ fn bench_lazy_reduction(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_horner_evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Horner Evaluation");
+    
+    let coefficients: Vec<Fp> = (0..64).map(|_| random_fp()).collect();
+    let poly = Polynomial::new(coefficients.clone());
+    let x = random_fp();
+    
+    group.bench_function("mul_then_add", |bench| {
+        bench.iter(|| {
+            let mut result = Fp::zero_with(x.modulus());
+            for coeff in coefficients.iter().rev() {
+                result = result * x.clone() + coeff.clone();
+            }
+            black_box(result)
+        })
+    });
+    
+    group.bench_function("mul_add", |bench| {
+        bench.iter(|| black_box(poly.evaluate(&x)))
+    });
+    
+    group.finish();
+}
+
 fn bench_gas_estimation(c: &mut Criterion) {
     // This benchmark simulates Arbitrum Stylus gas usage
-    let modulus = BigUint::from_str(BN254_MODULUS).unwrap();
     let mut group = c.benchmark_group("Stylus Gas Estimation");
     group.measurement_time(Duration::from_secs(5));
     
@@ -363,6 +412,20 @@ fn bench_gas_estimation(c: &mut Criterion) {
         })
     });
     
+    // Variable-time vs fixed-window exponentiation with a full-size exponent
+    let exp = random_fp().from_montgomery();
+    group.bench_function("pow_gas", |bench| {
+        bench.iter(|| {
+            black_box(a.pow_biguint(&exp))
+        })
+    });
+    
+    group.bench_function("pow_fixed_window_gas", |bench| {
+        bench.iter(|| {
+            black_box(a.pow_fixed_window(&exp, 4))
+        })
+    });
+    
     // Simulate a common ZK operation pattern: multiple multiplications followed by addition
     group.bench_function("zk_pattern_gas", |bench| {
         bench.iter(|| {
@@ -378,12 +441,15 @@ fn bench_gas_estimation(c: &mut Criterion) {
 
 criterion_group!(
     benches,
+    field_arithmetic_benchmark,
     bench_field_add,
     bench_field_mul,
     bench_field_inverse,
     bench_field_exponentiation,
+    bench_square_root,
     bench_montgomery_operations,
     bench_lazy_reduction,
+    bench_horner_evaluation,
     bench_gas_estimation,
 );
 criterion_main!(benches); 
\ No newline at end of file
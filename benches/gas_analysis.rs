@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use zerosync::arithmetic::field::Fp;
-use zerosync::curve::bn254::{BN254, G1Affine, G2Affine};
+use zerosync::curve::bn254::BN254;
+use zerosync::arithmetic::traits::Field;
 use num_bigint::BigUint;
 use std::str::FromStr;
 
@@ -66,6 +67,24 @@ fn curve_operations_benchmark(c: &mut Criterion) {
         });
     });
     
+    // G1 doubling loop
+    group.bench_function("G1 Doubling Loop", |bench| {
+        bench.iter(|| {
+            let mut p = g1.clone();
+            for _ in 0..16 {
+                p = p.double();
+            }
+            black_box(p);
+        });
+    });
+    
+    // G1 negation (in place on the y coordinate)
+    group.bench_function("G1 Negation", |bench| {
+        bench.iter(|| {
+            black_box(-g1.clone());
+        });
+    });
+    
     // G2 point addition
     group.bench_function("G2 Point Addition", |bench| {
         bench.iter(|| {
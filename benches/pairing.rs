@@ -0,0 +1,218 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::BigUint;
+use rand::Rng;
+use std::time::Duration;
+use zerosync::arithmetic::field::Fp;
+use zerosync::curve::bn254::{BN254, G1Affine, G2Affine};
+use zerosync::curve::scalar::Scalar;
+use zerosync::groth16::{prepare_inputs, verify, Proof, VerifyingKey};
+
+/// Random scalar below the group order
+fn random_scalar<R: Rng>(rng: &mut R) -> BigUint {
+    let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+    BigUint::from_bytes_le(&bytes) % Scalar::modulus()
+}
+
+fn msm_benchmark(c: &mut Criterion) {
+    let curve = BN254::new();
+    let g = curve.g1_generator();
+    let mut rng = rand::thread_rng();
+
+    let mut group = c.benchmark_group("G1 MSM");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    // Sizes straddle the naive / 5-bit / derived-window switch points in `msm`
+    for size in [1usize, 16, 31, 32, 64, 256, 1024] {
+        // Cheap distinct bases: small multiples of the generator
+        let mut bases = Vec::with_capacity(size);
+        let mut current = g.clone();
+        for _ in 0..size {
+            bases.push(current.clone());
+            current = current + g.clone();
+        }
+        let scalars: Vec<BigUint> = (0..size).map(|_| random_scalar(&mut rng)).collect();
+
+        let input = (bases, scalars);
+        group.bench_with_input(BenchmarkId::new("multi_mul", size), &input, |bench, (bases, scalars)| {
+            bench.iter(|| {
+                black_box(G1Affine::multi_mul(bases, scalars))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("msm", size), &input, |bench, (bases, scalars)| {
+            bench.iter(|| {
+                black_box(G1Affine::msm(bases, scalars))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn window_table_benchmark(c: &mut Criterion) {
+    let curve = BN254::new();
+    let base = curve.g1_generator() * 7;
+    let mut rng = rand::thread_rng();
+    let scalars: Vec<BigUint> = (0..100).map(|_| random_scalar(&mut rng)).collect();
+
+    let mut group = c.benchmark_group("G1 fixed base");
+    group.sample_size(10);
+
+    // One base times 100 scalars, rebuilding the table each time vs once
+    group.bench_function("windowed_mul_x100", |bench| {
+        bench.iter(|| {
+            for scalar in &scalars {
+                black_box(base.windowed_mul(scalar));
+            }
+        })
+    });
+
+    group.bench_function("window_table_x100", |bench| {
+        bench.iter(|| {
+            let table = base.precompute_window(4);
+            for scalar in &scalars {
+                black_box(table.mul(scalar));
+            }
+        })
+    });
+
+    group.bench_function("wnaf_mul_w5_x100", |bench| {
+        bench.iter(|| {
+            for scalar in &scalars {
+                black_box(base.wnaf_mul(scalar, 5));
+            }
+        })
+    });
+
+    group.bench_function("glv_mul_x100", |bench| {
+        bench.iter(|| {
+            for scalar in &scalars {
+                black_box(base.glv_mul(scalar));
+            }
+        })
+    });
+
+    let typed_scalars: Vec<Scalar> = scalars.iter().cloned().map(Scalar::new).collect();
+    group.bench_function("mul_scalar_x100", |bench| {
+        bench.iter(|| {
+            for scalar in &typed_scalars {
+                black_box(base.mul_scalar(scalar));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn groth16_benchmark(c: &mut Criterion) {
+    let curve = BN254::new();
+    let g1 = curve.g1_generator();
+    let g2 = curve.g2_generator();
+    let mut rng = rand::thread_rng();
+
+    let mut group = c.benchmark_group("Groth16");
+    group.sample_size(10);
+
+    for num_inputs in [1usize, 4, 16] {
+        let vk = VerifyingKey {
+            alpha_g1: g1.clone(),
+            beta_g2: g2.clone(),
+            gamma_g2: g2.clone(),
+            delta_g2: g2.clone(),
+            ic: (0..=num_inputs as u64).map(|i| g1.clone() * (i + 2)).collect(),
+        };
+        let inputs: Vec<Fp> = (0..num_inputs)
+            .map(|_| Fp::new(random_scalar(&mut rng), Scalar::modulus()))
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("prepare_inputs", num_inputs), &(vk.clone(), inputs.clone()), |bench, (vk, inputs)| {
+            bench.iter(|| {
+                black_box(prepare_inputs(vk, inputs).unwrap())
+            })
+        });
+
+        // Validity doesn't affect the cost, so any well-formed proof will do
+        let proof = Proof { a: g1.clone(), b: g2.clone(), c: g1.clone() };
+        group.bench_with_input(BenchmarkId::new("verify", num_inputs), &(vk, proof, inputs), |bench, (vk, proof, inputs)| {
+            bench.iter(|| {
+                black_box(verify(vk, proof, inputs).unwrap())
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn g2_decompression_benchmark(c: &mut Criterion) {
+    let curve = BN254::new();
+    let bytes = (curve.g2_generator() * 12345).to_compressed();
+
+    let mut group = c.benchmark_group("G2 Decompression");
+    group.sample_size(10);
+
+    group.bench_function("from_compressed", |bench| {
+        bench.iter(|| {
+            black_box(G2Affine::from_compressed(black_box(&bytes)).unwrap())
+        })
+    });
+
+    group.bench_function("from_compressed_unchecked", |bench| {
+        bench.iter(|| {
+            black_box(G2Affine::from_compressed_unchecked(black_box(&bytes)).unwrap())
+        })
+    });
+
+    group.finish();
+}
+
+fn pairing_benchmark(c: &mut Criterion) {
+    let curve = BN254::new();
+    let p = curve.g1_generator();
+    let q = curve.g2_generator();
+
+    let mut group = c.benchmark_group("Pairing");
+    group.sample_size(10);
+
+    group.bench_function("pairing", |bench| {
+        bench.iter(|| {
+            black_box(curve.pairing(black_box(&p), black_box(&q)))
+        })
+    });
+
+    group.bench_function("pairing_checked", |bench| {
+        bench.iter(|| {
+            black_box(curve.pairing_checked(black_box(&p), black_box(&q)).unwrap())
+        })
+    });
+
+    // Product of Miller loops with a single final exponentiation, as in `ecPairing`
+    for num_pairs in 2usize..=6 {
+        let pairs: Vec<(G1Affine, G2Affine)> = (0..num_pairs as u64)
+            .map(|i| (p.clone() * (i + 1), q.clone()))
+            .collect();
+        group.bench_with_input(BenchmarkId::new("pairing_check", num_pairs), &pairs, |bench, pairs| {
+            bench.iter(|| {
+                black_box(curve.pairing_check(black_box(pairs)))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn curve_instance_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("BN254 instance");
+
+    group.bench_function("new", |bench| {
+        bench.iter(|| black_box(BN254::new()))
+    });
+
+    group.bench_function("bn254", |bench| {
+        bench.iter(|| black_box(BN254::bn254()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, msm_benchmark, window_table_benchmark, pairing_benchmark, groth16_benchmark, g2_decompression_benchmark, curve_instance_benchmark);
+criterion_main!(benches);
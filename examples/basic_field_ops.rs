@@ -1,5 +1,4 @@
 use num_bigint::BigUint;
-use num_traits::Num;
 use std::str::FromStr;
 use zerosync::arithmetic::field::Fp;
 
@@ -0,0 +1,50 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use std::time::Instant;
+use zerosync::curve::bn254::{BN254, G1Affine};
+use zerosync::curve::scalar::Scalar;
+
+const NUM_POINTS: usize = 1000;
+
+fn main() {
+    println!("ZeroSync MSM Demo");
+    println!("=================");
+
+    let curve = BN254::new();
+    let g = curve.g1_generator();
+    let mut rng = rand::thread_rng();
+
+    // Random points as random multiples of the generator
+    println!("Generating {} random points and scalars...", NUM_POINTS);
+    let random_scalar = |rng: &mut rand::rngs::ThreadRng| {
+        let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+        BigUint::from_bytes_le(&bytes) % Scalar::modulus()
+    };
+    let bases: Vec<G1Affine> = (0..NUM_POINTS)
+        .map(|_| g.windowed_mul(&random_scalar(&mut rng)))
+        .collect();
+    let scalars: Vec<BigUint> = (0..NUM_POINTS).map(|_| random_scalar(&mut rng)).collect();
+
+    // Naive: one windowed multiplication per point, then sum
+    let start = Instant::now();
+    let naive = bases
+        .iter()
+        .zip(scalars.iter())
+        .fold(G1Affine::identity(&curve.modulus), |acc, (base, scalar)| {
+            acc + base.windowed_mul(scalar)
+        });
+    let naive_time = start.elapsed();
+    println!("Naive windowed_mul fold: {:?}", naive_time);
+
+    // Pippenger
+    let start = Instant::now();
+    let msm = G1Affine::msm(&bases, &scalars);
+    let msm_time = start.elapsed();
+    println!("G1Affine::msm:           {:?}", msm_time);
+
+    assert_eq!(naive, msm, "MSM result differs from the naive sum");
+    println!(
+        "\nResults match. Speedup: {:.1}x",
+        naive_time.as_secs_f64() / msm_time.as_secs_f64()
+    );
+}
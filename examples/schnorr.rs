@@ -0,0 +1,78 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use zerosync::curve::bn254::{BN254, G1Affine};
+use zerosync::curve::scalar::Scalar;
+use zerosync::transcript::Transcript;
+
+/// A Schnorr signature `(R, s)` with `s·G = R + e·pk`
+struct Signature {
+    r: G1Affine,
+    s: Scalar,
+}
+
+/// Samples a secret key and returns it with `pk = sk·G`
+fn keygen<R: Rng>(rng: &mut R) -> (Scalar, G1Affine) {
+    let bytes: [u8; 64] = std::array::from_fn(|_| rng.gen());
+    let sk = Scalar::new(BigUint::from_bytes_le(&bytes));
+    let pk = BN254::new().g1_generator().windowed_mul(&sk.to_biguint());
+    (sk, pk)
+}
+
+/// Fiat-Shamir challenge `e = H(R, pk, msg)`
+fn challenge(r: &G1Affine, pk: &G1Affine, msg: &[u8]) -> Scalar {
+    let mut transcript = Transcript::new(b"zerosync-schnorr");
+    transcript.append_message(b"R", &r.to_uncompressed());
+    transcript.append_message(b"pk", &pk.to_uncompressed());
+    transcript.append_message(b"msg", msg);
+    Scalar::from_base(&transcript.challenge_scalar(b"e"))
+}
+
+fn sign(sk: &Scalar, pk: &G1Affine, msg: &[u8]) -> Signature {
+    // Deterministic nonce bound to the key and message, so it is never reused
+    let mut nonce_input = sk.to_biguint().to_bytes_be();
+    nonce_input.extend_from_slice(msg);
+    let k = Scalar::from_hash(b"zerosync-schnorr-nonce", &nonce_input);
+
+    let r = BN254::new().g1_generator().windowed_mul(&k.to_biguint());
+    let e = challenge(&r, pk, msg);
+    let s = k + e * sk.clone();
+    Signature { r, s }
+}
+
+fn verify(pk: &G1Affine, msg: &[u8], signature: &Signature) -> bool {
+    let g = BN254::new().g1_generator();
+    let e = challenge(&signature.r, pk, msg);
+
+    let lhs = g.windowed_mul(&signature.s.to_biguint());
+    let rhs = &signature.r + &pk.windowed_mul(&e.to_biguint());
+    lhs == rhs
+}
+
+fn main() {
+    println!("ZeroSync Schnorr Signature Demo");
+    println!("===============================");
+
+    let mut rng = rand::thread_rng();
+    let (sk, pk) = keygen(&mut rng);
+    println!("Public key: {}", hex(&pk.to_uncompressed()));
+
+    let msg = b"transfer 10 tokens to alice";
+    let signature = sign(&sk, &pk, msg);
+    println!("R: {}", hex(&signature.r.to_uncompressed()));
+    println!("s: {:x}", signature.s.to_biguint());
+
+    assert!(verify(&pk, msg, &signature), "valid signature must verify");
+    println!("\nValid signature verifies");
+
+    let tampered = b"transfer 99 tokens to alice";
+    assert!(!verify(&pk, tampered, &signature), "tampered message must not verify");
+    println!("Tampered message is rejected");
+
+    let (_, other_pk) = keygen(&mut rng);
+    assert!(!verify(&other_pk, msg, &signature), "signature must not verify under another key");
+    println!("Signature is rejected under a different public key");
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
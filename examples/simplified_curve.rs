@@ -25,6 +25,7 @@ impl Field {
         }
     }
     
+    #[allow(dead_code)]
     fn one(modulus: &BigUint) -> Self {
         Self {
             value: BigUint::from(1u32),
@@ -1,4 +1,5 @@
 use num_bigint::BigUint;
+use std::fmt;
 use std::str::FromStr;
 use std::ops::{Add, Sub, Mul, Div, Neg};
 
@@ -19,9 +20,6 @@ impl SimpleField {
         }
     }
     
-    fn to_string(&self) -> String {
-        self.value.to_string()
-    }
     
     fn pow(&self, exp: u32) -> Self {
         let result = self.value.modpow(&BigUint::from(exp), &self.modulus);
@@ -67,6 +65,12 @@ impl SimpleField {
     }
 }
 
+impl fmt::Display for SimpleField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
 impl Add for SimpleField {
     type Output = Self;
     
@@ -153,38 +157,38 @@ fn main() {
     // Create some field elements
     let a = SimpleField::new(BigUint::from(30u32), modulus.clone());
     let b = SimpleField::new(BigUint::from(50u32), modulus.clone());
-    println!("a = {}, b = {}", a.to_string(), b.to_string());
+    println!("a = {}, b = {}", a, b);
     
     // Addition
     let sum = a.clone() + b.clone();
-    println!("a + b = {}", sum.to_string());
+    println!("a + b = {}", sum);
     
     // Subtraction
     let diff = a.clone() - b.clone();
-    println!("a - b = {}", diff.to_string());
+    println!("a - b = {}", diff);
     
     // Multiplication
     let product = a.clone() * b.clone();
-    println!("a * b = {}", product.to_string());
+    println!("a * b = {}", product);
     
     // Negation
     let neg_a = -a.clone();
-    println!("-a = {}", neg_a.to_string());
+    println!("-a = {}", neg_a);
     
     // Inverse
     if let Some(inv_a) = a.clone().inverse() {
-        println!("a^(-1) = {}", inv_a.to_string());
+        println!("a^(-1) = {}", inv_a);
     } else {
         println!("a has no inverse");
     }
     
     // Division
     let quotient = a.clone() / b.clone();
-    println!("a / b = {}", quotient.to_string());
+    println!("a / b = {}", quotient);
     
     // Power
     let power = a.pow(3);
-    println!("a^3 = {}", power.to_string());
+    println!("a^3 = {}", power);
     
     // Try with BN254 curve parameters
     println!("\nBN254 Curve Demonstration");
@@ -199,18 +203,18 @@ fn main() {
     // Create field elements
     let c = SimpleField::new(BigUint::from(12345u32), bn254_modulus.clone());
     let d = SimpleField::new(BigUint::from(67890u32), bn254_modulus.clone());
-    println!("c = {}, d = {}", c.to_string(), d.to_string());
+    println!("c = {}, d = {}", c, d);
     
     // Addition
     let sum2 = c.clone() + d.clone();
-    println!("c + d = {}", sum2.to_string());
+    println!("c + d = {}", sum2);
     
     // Multiplication
     let product2 = c.clone() * d.clone();
-    println!("c * d = {}", product2.to_string());
+    println!("c * d = {}", product2);
     
     // Verify with direct computation
     let expected = (BigUint::from(12345u32) * BigUint::from(67890u32)) % &bn254_modulus;
-    println!("Expected c * d = {}", expected.to_string());
+    println!("Expected c * d = {}", expected);
     assert_eq!(product2.value, expected);
 } 
\ No newline at end of file
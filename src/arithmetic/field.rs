@@ -5,21 +5,49 @@ use std::sync::RwLock;
 use lazy_static::lazy_static;
 use num_bigint::BigUint;
 use std::cmp::Ordering;
-use num_traits::{Zero, One, ToPrimitive};
-use num_integer::Integer;
+use std::fmt;
+use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use num_traits::{Num, Zero, One, ToPrimitive};
+use crate::curve::scalar::Scalar;
 use crate::arithmetic::{
     traits::{Field, PrimeField},
-    montgomery::{MontgomeryConstants, MontgomeryForm, mont_mul, ct_lt, to_limbs, to_bytes},
+    montgomery::{MontgomeryConstants, MontgomeryForm, mont_mul, mont_mul_ct, to_limbs, to_bytes},
 };
 use std::str::FromStr;
+#[cfg(feature = "prover")]
+use rand::RngCore;
+use thiserror::Error;
 
 /// Word size for field operations
 const WORD_SIZE: u32 = 64;
 const WORDS_PER_LIMB: usize = 4;
 
+/// Modulus used by `Zero::zero()` and `One::one()` (the BN254 base field)
+const DEFAULT_MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// Width-4 sliding-window chain for `(p + 1) / 4` over the BN254 base field
+///
+/// Each step squares the accumulator the given number of times, then multiplies by
+/// the odd power `a^digit`. The first step only loads its digit.
+const SQRT_CHAIN: [(u8, u8); 48] = [
+    (0, 3), (7, 3), (3, 1), (7, 9), (2, 3), (5, 7), (6, 11), (1, 1),
+    (8, 9), (1, 1), (7, 13), (10, 5), (6, 13), (2, 3), (7, 5), (6, 1),
+    (7, 11), (5, 13), (3, 5), (8, 3), (9, 5), (3, 3), (8, 11), (4, 11),
+    (6, 11), (2, 3), (10, 11), (4, 5), (5, 9), (7, 11), (2, 1), (7, 7),
+    (6, 7), (5, 5), (2, 1), (7, 13), (6, 15), (5, 1), (6, 1), (5, 3),
+    (9, 11), (5, 13), (1, 1), (8, 15), (4, 9), (4, 15), (3, 5), (5, 9),
+];
+
+/// Squarings applied after the last step of `SQRT_CHAIN`
+const SQRT_CHAIN_TRAILING_SQUARINGS: u8 = 1;
+
 // Cache for commonly used field elements and constants
 lazy_static! {
     static ref FIELD_CACHE: RwLock<HashMap<BigUint, Arc<FieldCache>>> = RwLock::new(HashMap::new());
+    static ref DEFAULT_FIELD_MODULUS: BigUint = BigUint::from_str(DEFAULT_MODULUS).unwrap();
 }
 
 struct FieldCache {
@@ -34,20 +62,20 @@ struct FieldCache {
 
 impl FieldCache {
     fn new(modulus: &BigUint) -> Self {
-        let constants = MontgomeryConstants::new(modulus, 64);
-        let zero = MontgomeryForm::new(vec![0; 4], constants.clone());
+        let constants = MontgomeryConstants::new(modulus, WORD_SIZE);
+        let zero = MontgomeryForm::new(vec![0; WORDS_PER_LIMB], constants.clone());
         let one = {
-            let mut value = vec![0; 4];
+            let mut value = vec![0; WORDS_PER_LIMB];
             value[0] = 1;
             MontgomeryForm::new(value, constants.clone())
         };
 
-        // Pre-compute small values up to 16
+        // Pre-compute small values below 16
         let mut small_values = Vec::with_capacity(16);
-        let mut current = one.clone();
+        let mut current = zero.clone();
         for _ in 0..16 {
             small_values.push(current.clone());
-            current = current.mul(&one);
+            current = current.add(&one);
         }
 
         Self {
@@ -58,6 +86,34 @@ impl FieldCache {
         }
     }
 
+    fn get(modulus: &BigUint) -> Arc<FieldCache> {
+        // Try to get cached constants - handle RwLock errors gracefully
+        let cache = match FIELD_CACHE.read() {
+            Ok(cache_map) => {
+                cache_map.get(modulus).cloned()
+            },
+            Err(_) => {
+                // If the lock is poisoned, we'll create a new cache instance
+                None
+            },
+        };
+
+        match cache {
+            Some(cache) => cache,
+            None => {
+                // Create new cache entry - handle RwLock errors gracefully
+                let cache = Arc::new(FieldCache::new(modulus));
+                
+                // Try to update the cache, but continue even if it fails
+                let _ = FIELD_CACHE.write().map(|mut cache_map| {
+                    cache_map.insert(modulus.clone(), cache.clone());
+                });
+                
+                cache
+            }
+        }
+    }
+
     fn get_small_value(&self, value: u64) -> Option<MontgomeryForm> {
         if value < self.small_values.len() as u64 {
             Some(self.small_values[value as usize].clone())
@@ -67,13 +123,76 @@ impl FieldCache {
     }
 }
 
+/// Size in bytes of a serialized field element
+pub const FIELD_ELEMENT_BYTES: usize = 32;
+
+/// Errors returned when decoding field elements
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FieldError {
+    /// The input is not a whole number of 32-byte elements
+    #[error("input length {0} is not a multiple of 32 bytes")]
+    InvalidLength(usize),
+    /// An element in a batch is not below the modulus
+    #[error("element {index} is not canonical: a {got_bits}-bit value is not below the modulus")]
+    NonCanonical { index: usize, got_bits: u64 },
+    /// The radix is outside the supported range `2..=36`
+    #[error("unsupported radix {0}")]
+    InvalidRadix(u32),
+    /// The string is not a valid number in the given radix
+    #[error("invalid digits for radix {radix}")]
+    InvalidDigits { radix: u32 },
+    /// A single encoded element is not below the modulus
+    #[error("element out of range: a {got_bits}-bit value is not below the {modulus_bits}-bit modulus")]
+    OutOfRange { got_bits: u64, modulus_bits: u64 },
+}
+
+/// Result type for fallible field operations
+pub type FieldResult<T> = Result<T, FieldError>;
+
 /// Represents an element of a prime field using Montgomery arithmetic
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// Elements of different fields are never equal, and mixing them in `+`, `-` or
+/// `*` panics. `Zero::zero()` and `One::one()` have no modulus to work with, so
+/// they are created in the BN254 base field; use `zero_with`/`one_with`, or
+/// `Field::zero_like`/`Field::one_like` in generic code, for any other field.
+///
+/// The modulus should be an odd prime. Odd composite moduli are accepted, but then
+/// only elements coprime to the modulus are invertible and `inverse` returns `None`
+/// for the rest; even moduli are rejected by the Montgomery setup.
+#[derive(Clone)]
 pub struct Fp {
     /// The value in Montgomery form
     mont_form: MontgomeryForm,
 }
 
+impl PartialEq for Fp {
+    fn eq(&self, other: &Self) -> bool {
+        // `MontgomeryForm` equality also requires matching moduli
+        self.mont_form == other.mont_form
+    }
+}
+
+impl Eq for Fp {}
+
+impl Hash for Fp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash the canonical value rather than the (possibly lazily reduced) Montgomery limbs
+        self.mont_form.constants.modulus.hash(state);
+        self.from_montgomery().to_bytes_be().hash(state);
+    }
+}
+
+impl fmt::Debug for Fp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The Montgomery limbs are unreadable and may be lazily reduced, so show the
+        // canonical value instead; `raw()` exposes the limbs when they are needed
+        f.debug_struct("Fp")
+            .field("value", &self.from_montgomery())
+            .field("modulus", &self.mont_form.constants.modulus)
+            .finish()
+    }
+}
+
 impl PartialOrd for Fp {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         // First, make sure we're working with the same modulus
@@ -92,31 +211,7 @@ impl PartialOrd for Fp {
 impl Fp {
     /// Creates a new field element
     pub fn new(value: BigUint, modulus: BigUint) -> Self {
-        // Try to get cached constants - handle RwLock errors gracefully
-        let cache = match FIELD_CACHE.read() {
-            Ok(cache_map) => {
-                cache_map.get(&modulus).cloned()
-            },
-            Err(_) => {
-                // If the lock is poisoned, we'll create a new cache instance
-                None
-            },
-        };
-
-        let cache = match cache {
-            Some(cache) => cache,
-            None => {
-                // Create new cache entry - handle RwLock errors gracefully
-                let cache = Arc::new(FieldCache::new(&modulus));
-                
-                // Try to update the cache, but continue even if it fails
-                let _ = FIELD_CACHE.write().map(|mut cache_map| {
-                    cache_map.insert(modulus.clone(), cache.clone());
-                });
-                
-                cache
-            }
-        };
+        let cache = FieldCache::get(&modulus);
 
         // Check if it's a small value first
         if let Some(small_value) = value.to_u64().and_then(|v| cache.get_small_value(v)) {
@@ -125,101 +220,521 @@ impl Fp {
 
         // Convert to Montgomery form
         let reduced_value = value % &cache.constants.modulus;
-        let mut mont_form = MontgomeryForm::new(
-            to_limbs(&reduced_value, 4),
+        let mont_form = MontgomeryForm::new(
+            to_limbs(&reduced_value, WORDS_PER_LIMB),
             cache.constants.clone(),
         );
 
-        // Convert to Montgomery form by multiplying by R^2 mod N
-        let r_squared_limbs = to_limbs(&cache.constants.r_squared, 4);
-        let modulus_limbs = to_limbs(&cache.constants.modulus, 4);
-        let n_prime_limbs = to_limbs(&cache.constants.n_prime, 4);
-        
-        mont_form.value = mont_mul(
-            &mont_form.value,
-            &r_squared_limbs,
-            &modulus_limbs,
-            &n_prime_limbs
+        Self { mont_form }
+    }
+
+    /// Creates a new field element from a `u64`
+    pub fn from_u64(value: u64, modulus: BigUint) -> Self {
+        Self::new(BigUint::from(value), modulus)
+    }
+
+    /// Parses a field element from a string in the given radix, reducing modulo `modulus`
+    pub fn from_str_radix(s: &str, radix: u32, modulus: BigUint) -> Result<Self, FieldError> {
+        if !(2..=36).contains(&radix) {
+            return Err(FieldError::InvalidRadix(radix));
+        }
+        let value = BigUint::from_str_radix(s, radix)
+            .map_err(|_| FieldError::InvalidDigits { radix })?;
+        Ok(Self::new(value, modulus))
+    }
+
+    /// Converts a scalar into the BN254 base field
+    ///
+    /// `r < p`, so the canonical value is preserved.
+    pub fn from_scalar(s: &Scalar) -> Self {
+        Self::new(s.to_biguint(), DEFAULT_FIELD_MODULUS.clone())
+    }
+
+    /// Samples a uniformly random element by rejection sampling
+    ///
+    /// Draws exactly `modulus.bits()` random bits and retries while the value is not
+    /// below the modulus. Unlike reducing a wider sample, this has no bias toward
+    /// small values, and each attempt succeeds with probability above one half.
+    #[cfg(feature = "prover")]
+    pub fn from_random_bytes<R: RngCore + ?Sized>(rng: &mut R, modulus: &BigUint) -> Self {
+        let bits = modulus.bits();
+        let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+        let top_mask = 0xffu8 >> ((8 - bits % 8) % 8);
+        loop {
+            rng.fill_bytes(&mut bytes);
+            bytes[0] &= top_mask;
+            let value = BigUint::from_bytes_be(&bytes);
+            if &value < modulus {
+                return Self::new(value, modulus.clone());
+            }
+        }
+    }
+
+    /// Creates a field element from four little-endian 64-bit limbs, reducing modulo `modulus`
+    ///
+    /// This is the `[u64; 4]` layout used by arkworks and halo2 for canonical values.
+    pub fn from_u64_array(limbs: [u64; 4], modulus: BigUint) -> Self {
+        let value = BigUint::from_bytes_le(&to_bytes(&limbs));
+        Self::new(value, modulus)
+    }
+
+    /// Returns the canonical (non-Montgomery) value as four little-endian 64-bit limbs
+    pub fn to_u64_array(&self) -> [u64; 4] {
+        self.to_canonical_limbs()
+    }
+
+    /// Encodes the canonical value as 32 big-endian bytes
+    pub fn to_bytes_be(&self) -> [u8; FIELD_ELEMENT_BYTES] {
+        let mut out = [0u8; FIELD_ELEMENT_BYTES];
+        for (chunk, limb) in out.chunks_exact_mut(8).zip(self.to_canonical_limbs().iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    /// Encodes the canonical value as 32 little-endian bytes
+    ///
+    /// Like `to_bytes_be`, the output does not depend on the lazy-reduction state
+    /// of the internal representation.
+    pub fn to_bytes_le(&self) -> [u8; FIELD_ELEMENT_BYTES] {
+        let mut out = [0u8; FIELD_ELEMENT_BYTES];
+        for (chunk, limb) in out.chunks_exact_mut(8).zip(self.to_canonical_limbs().iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        out
+    }
+
+    /// Decodes 32 big-endian bytes produced by `to_bytes_be`
+    ///
+    /// Unlike `new`, values at or above `modulus` are rejected rather than reduced,
+    /// so every element has exactly one accepted encoding. Verifiers reading
+    /// untrusted input should use this.
+    pub fn from_bytes_be(bytes: &[u8; FIELD_ELEMENT_BYTES], modulus: BigUint) -> FieldResult<Self> {
+        Self::from_canonical(BigUint::from_bytes_be(bytes), modulus)
+    }
+
+    /// Decodes 32 little-endian bytes produced by `to_bytes_le`, rejecting
+    /// non-canonical values like `from_bytes_be`
+    pub fn from_bytes_le(bytes: &[u8; FIELD_ELEMENT_BYTES], modulus: BigUint) -> FieldResult<Self> {
+        Self::from_canonical(BigUint::from_bytes_le(bytes), modulus)
+    }
+
+    /// Converts canonical little-endian limbs (already below `modulus`) to Montgomery form
+    ///
+    /// Uses a single fixed-time Montgomery multiplication by `R²`, so the running time
+    /// depends only on the modulus, not on the value.
+    pub(crate) fn from_canonical_limbs_ct(limbs: &[u64; WORDS_PER_LIMB], modulus: &BigUint) -> Self {
+        let constants = FieldCache::get(modulus).constants.clone();
+        let value = mont_mul_ct(
+            limbs,
+            &to_limbs(&constants.r_squared, WORDS_PER_LIMB),
+            &to_limbs(&constants.modulus, WORDS_PER_LIMB),
+            &to_limbs(&constants.n_prime, WORDS_PER_LIMB),
         );
+        Self { mont_form: MontgomeryForm { value, extra_precision: 0, constants } }
+    }
 
-        Self { mont_form }
+    fn from_canonical(value: BigUint, modulus: BigUint) -> FieldResult<Self> {
+        if value >= modulus {
+            return Err(FieldError::OutOfRange { got_bits: value.bits(), modulus_bits: modulus.bits() });
+        }
+        Ok(Self::new(value, modulus))
+    }
+
+    /// Encodes elements as consecutive 32-byte big-endian chunks in a single buffer
+    ///
+    /// The inverse of `batch_from_bytes`.
+    pub fn batch_to_bytes(elems: &[Fp]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(elems.len() * FIELD_ELEMENT_BYTES);
+        for elem in elems {
+            bytes.extend_from_slice(&elem.to_bytes_be());
+        }
+        bytes
+    }
+
+    /// Decodes a concatenation of 32-byte big-endian elements
+    ///
+    /// Every chunk must already be below the modulus; the first one that is not is
+    /// reported by its index.
+    pub fn batch_from_bytes(bytes: &[u8], modulus: &BigUint) -> Result<Vec<Fp>, FieldError> {
+        if !bytes.len().is_multiple_of(FIELD_ELEMENT_BYTES) {
+            return Err(FieldError::InvalidLength(bytes.len()));
+        }
+
+        bytes
+            .chunks(FIELD_ELEMENT_BYTES)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let value = BigUint::from_bytes_be(chunk);
+                if &value >= modulus {
+                    return Err(FieldError::NonCanonical { index, got_bits: value.bits() });
+                }
+                Ok(Self::new(value, modulus.clone()))
+            })
+            .collect()
     }
 
     /// Converts the value from Montgomery form
     pub fn from_montgomery(&self) -> BigUint {
-        let mut mont_form = self.mont_form.clone();
-        
-        // Make sure the value is fully reduced before converting
-        mont_form.reduce();
-        
-        // Convert from Montgomery form by multiplying by 1
-        let one_limbs = vec![1u64; 4];
-        let modulus_limbs = to_limbs(&mont_form.constants.modulus, 4);
-        let n_prime_limbs = to_limbs(&mont_form.constants.n_prime, 4);
-        
-        let result_limbs = mont_mul(
-            &mont_form.value,
-            &one_limbs,
-            &modulus_limbs,
-            &n_prime_limbs
-        );
-        
-        // Convert to BigUint safely
-        BigUint::from_bytes_le(&to_bytes(&result_limbs))
+        BigUint::from_bytes_le(&to_bytes(&self.to_canonical_limbs()))
+    }
+
+    /// Returns the canonical value as little-endian 64-bit limbs without building a `BigUint`
+    ///
+    /// Multiplying by one maps any value below `R` into `[0, N]` before the final
+    /// conditional subtraction, so lazily reduced values need no separate `reduce`.
+    pub fn to_canonical_limbs(&self) -> [u64; WORDS_PER_LIMB] {
+        let constants = &self.mont_form.constants;
+        let mut modulus_limbs = [0u64; WORDS_PER_LIMB];
+        for (limb, digit) in modulus_limbs.iter_mut().zip(constants.modulus.iter_u64_digits()) {
+            *limb = digit;
+        }
+        // Montgomery multiplication only reads the low word of `n'`
+        let n_prime = [constants.n_prime.iter_u64_digits().next().unwrap_or(0)];
+        let mut one = [0u64; WORDS_PER_LIMB];
+        one[0] = 1;
+
+        let result = mont_mul(&self.mont_form.value, &one, &modulus_limbs, &n_prime);
+        let mut limbs = [0u64; WORDS_PER_LIMB];
+        limbs.copy_from_slice(&result);
+        limbs
     }
 
     pub fn square(&mut self) -> Self {
         let mut result = self.clone();
-        let base_mont = self.mont_form.clone();
-        result.mont_form = base_mont.clone().mul(&base_mont);
+        result.mont_form = self.mont_form.square();
         result
     }
     
-    /// Get the modulus of this field element
-    pub fn modulus(&self) -> BigUint {
-        self.mont_form.constants.modulus.clone()
+    /// Returns `self⁻¹`, or zero when `self` is zero
+    ///
+    /// This is the "invert or zero" convention (`x^(p-2)` in a prime field): it
+    /// never fails, so verifier code can keep multiplying without branching on
+    /// `None`. Callers that must reject zero should use `inverse` instead. With a
+    /// composite modulus every non-invertible element maps to zero as well.
+    pub fn inverse_or_zero(&self) -> Fp {
+        self.inverse().unwrap_or_else(|| Self::zero_with(self.modulus()))
     }
 
-    fn inverse(&self) -> Option<Self> {
-        if self.is_zero() {
+    /// Computes `self * b + c`
+    ///
+    /// The product is left lazily reduced and the sum is reduced once at the end,
+    /// without building an intermediate element. This is the inner step of Horner
+    /// evaluation.
+    pub fn mul_add(&self, b: &Fp, c: &Fp) -> Fp {
+        self.assert_same_field(b);
+        self.assert_same_field(c);
+        Self { mont_form: self.mont_form.mul_add(&b.mont_form, &c.mont_form) }
+    }
+
+    /// Raises this element to an arbitrary-size power using square-and-multiply
+    pub fn pow_biguint(&self, exp: &BigUint) -> Self {
+        let mut result = self.one_like();
+        for i in (0..exp.bits()).rev() {
+            result = Field::square(&result);
+            if exp.bit(i) {
+                result = result * self.clone();
+            }
+        }
+        result
+    }
+
+    /// Raises this element to a power using the balanced-ternary digits of `exp`
+    ///
+    /// Each digit costs a cube and at most one multiplication by `self` or its
+    /// inverse. Zero has no inverse, so it falls back to `pow_biguint`.
+    pub fn pow_ternary(&self, exp: &BigUint) -> Self {
+        let inverse = match self.inverse() {
+            Some(inverse) => inverse,
+            None => return self.pow_biguint(exp),
+        };
+
+        let mut result = self.one_like();
+        for &digit in recode_ternary(exp).iter().rev() {
+            result = Field::square(&result) * result;
+            match digit {
+                1 => result = result * self.clone(),
+                -1 => result = result * inverse.clone(),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Raises this element to a power using a fixed-window ladder
+    ///
+    /// The number of squarings and table multiplications depends only on the bit
+    /// length of the modulus and on `window`, not on the exponent, so the gas cost
+    /// is the same for every exponent below the modulus.
+    pub fn pow_fixed_window(&self, exp: &BigUint, window: usize) -> Self {
+        assert!((1..=8).contains(&window), "Window size must be between 1 and 8 bits");
+
+        // Precompute self^0 .. self^(2^window - 1)
+        let mut table = Vec::with_capacity(1 << window);
+        table.push(self.one_like());
+        for i in 1..(1 << window) {
+            let next = table[i - 1].clone() * self.clone();
+            table.push(next);
+        }
+
+        let bits = exp.bits().max(self.mont_form.constants.modulus.bits()) as usize;
+        let num_windows = bits.div_ceil(window);
+
+        let mut result = table[0].clone();
+        for w in (0..num_windows).rev() {
+            for _ in 0..window {
+                result = Field::square(&result);
+            }
+
+            let mut index = 0usize;
+            for bit in 0..window {
+                if exp.bit((w * window + bit) as u64) {
+                    index |= 1 << bit;
+                }
+            }
+            result = result * table[index].clone();
+        }
+
+        result
+    }
+
+    /// Returns the canonical square root, or `None` if this is not a quadratic residue
+    ///
+    /// Of the two roots `r` and `-r`, the one with the smaller canonical value is
+    /// returned, so the result does not depend on which branch found the root.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.sqrt_both().map(|(smaller, _)| smaller);
+        if let Some(root) = &root {
+            debug_assert_eq!(Field::square(root), *self, "sqrt returned a non-root");
+        }
+        root
+    }
+
+    /// Returns both square roots ordered by canonical value, or `None` for non-residues
+    pub fn sqrt_both(&self) -> Option<(Self, Self)> {
+        let root = self.tonelli_shanks()?;
+        if Field::square(&root) != *self {
             return None;
         }
 
-        // Convert from Montgomery form
-        let mut a = self.mont_form.value.clone();
-        let modulus_limbs = to_limbs(&self.mont_form.constants.modulus, WORDS_PER_LIMB);
-        let one_limbs = vec![1u64; WORDS_PER_LIMB];
-        let n_prime_limbs = to_limbs(&self.mont_form.constants.n_prime, WORDS_PER_LIMB);
+        let neg_root = -root.clone();
+        if root.from_montgomery() <= neg_root.from_montgomery() {
+            Some((root, neg_root))
+        } else {
+            Some((neg_root, root))
+        }
+    }
 
-        // Convert from Montgomery form
-        a = mont_mul(&a, &one_limbs, &modulus_limbs, &n_prime_limbs);
+    /// Returns the Legendre symbol: 0 for zero, 1 for non-zero squares and -1 otherwise
+    ///
+    /// Evaluated as a Jacobi symbol with quadratic reciprocity, which needs only
+    /// shifts and remainders instead of the `(p - 1) / 2` exponentiation of Euler's
+    /// criterion. For a composite modulus, -1 still proves a non-square but 1 does not
+    /// prove a square.
+    pub fn legendre(&self) -> i8 {
+        let mut a = self.from_montgomery();
+        let mut n = self.modulus();
+        let low_bits = |x: &BigUint| x.iter_u64_digits().next().unwrap_or(0);
+        let mut symbol = 1i8;
 
-        // Convert to BigUint for inverse calculation
-        let a_biguint = BigUint::from_bytes_le(&to_bytes(&a));
-        let modulus = self.mont_form.constants.modulus.clone();
+        while !a.is_zero() {
+            // (2/n) = -1 exactly when n ≡ 3, 5 (mod 8)
+            let twos = a.trailing_zeros().unwrap_or(0);
+            a >>= twos;
+            if twos % 2 == 1 && matches!(low_bits(&n) & 7, 3 | 5) {
+                symbol = -symbol;
+            }
 
-        // Implementation of modular inverse using extended Euclidean algorithm
-        let (g, mut x) = mod_inverse(&a_biguint, &modulus);
-        
-        // Check if gcd is 1 (meaning inverse exists)
-        if g != BigUint::one() {
+            // Reciprocity flips the sign when both are 3 (mod 4)
+            if low_bits(&a) & 3 == 3 && low_bits(&n) & 3 == 3 {
+                symbol = -symbol;
+            }
+            std::mem::swap(&mut a, &mut n);
+            a %= &n;
+        }
+
+        if n.is_one() { symbol } else { 0 }
+    }
+
+    /// Returns how many square roots this element has: 0, 1 (for zero) or 2
+    pub fn square_root_count(&self) -> usize {
+        match self.sqrt_both() {
+            None => 0,
+            Some(_) if self.is_zero() => 1,
+            Some(_) => 2,
+        }
+    }
+
+    /// Computes `self^((p + 1) / 4)` for the BN254 base field using `SQRT_CHAIN`
+    ///
+    /// This takes 251 squarings and 54 multiplications, against roughly 108
+    /// multiplications for square-and-multiply over the same exponent.
+    fn sqrt_exp_chain(&self) -> Self {
+        // Odd powers a, a³, ..., a¹⁵
+        let square = Field::square(self);
+        let mut table = vec![self.clone()];
+        for i in 1..8 {
+            table.push(table[i - 1].clone() * square.clone());
+        }
+
+        let mut result = table[(SQRT_CHAIN[0].1 >> 1) as usize].clone();
+        for &(squarings, digit) in &SQRT_CHAIN[1..] {
+            for _ in 0..squarings {
+                result = Field::square(&result);
+            }
+            result = result * table[(digit >> 1) as usize].clone();
+        }
+        for _ in 0..SQRT_CHAIN_TRAILING_SQUARINGS {
+            result = Field::square(&result);
+        }
+
+        result
+    }
+
+    /// Finds some square root with Tonelli-Shanks, taking the `(p + 1) / 4` shortcut when `p ≡ 3 (mod 4)`
+    ///
+    /// Non-residues are rejected up front by `legendre`. For the BN254 base field the
+    /// candidate then comes straight from `sqrt_exp_chain`, so callers must still
+    /// verify it by squaring.
+    fn tonelli_shanks(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self.clone());
+        }
+        if self.legendre() == -1 {
             return None;
         }
-        
-        // Ensure x is reduced modulo the modulus
-        x = x % &modulus;
 
-        // Convert back to Montgomery form
-        let mut result = Self::new(x, modulus);
-        result.mont_form.reduce();
-        Some(result)
+        let modulus = self.modulus();
+        if modulus == *DEFAULT_FIELD_MODULUS {
+            return Some(self.sqrt_exp_chain());
+        }
+
+        let one = self.one_like();
+        let p_minus_one = &modulus - 1u32;
+
+        // Euler's criterion, since a Jacobi symbol of 1 does not prove a square for composite moduli
+        if self.pow_biguint(&(&p_minus_one >> 1)) != one {
+            return None;
+        }
+
+        if modulus.bit(0) && modulus.bit(1) {
+            return Some(self.pow_biguint(&((&modulus + 1u32) >> 2)));
+        }
+
+        // p - 1 = q * 2^s with q odd
+        let s = p_minus_one.trailing_zeros().unwrap_or(0);
+        let q = &p_minus_one >> s;
+
+        // Any quadratic non-residue
+        let minus_one = -one.clone();
+        let mut z = Self::new(BigUint::from(2u32), modulus.clone());
+        while z.pow_biguint(&(&p_minus_one >> 1)) != minus_one {
+            z = z + one.clone();
+        }
+
+        let mut m = s;
+        let mut c = z.pow_biguint(&q);
+        let mut t = self.pow_biguint(&q);
+        let mut root = self.pow_biguint(&((&q + 1u32) >> 1));
+
+        while t != one {
+            // Least i with t^(2^i) = 1
+            let mut i = 0;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = Field::square(&t_pow);
+                i += 1;
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = Field::square(&b);
+            }
+
+            m = i;
+            c = Field::square(&b);
+            t = t * c.clone();
+            root = root * b;
+        }
+
+        Some(root)
+    }
+
+    /// Returns the additive identity of the field with the given modulus
+    pub fn zero_with(modulus: BigUint) -> Self {
+        let cache = FieldCache::get(&modulus);
+        Self { mont_form: cache.zero.clone() }
+    }
+
+    /// Returns the multiplicative identity of the field with the given modulus
+    pub fn one_with(modulus: BigUint) -> Self {
+        let cache = FieldCache::get(&modulus);
+        Self { mont_form: cache.one.clone() }
+    }
+
+    /// Negates this element in place
+    pub fn neg_assign(&mut self) {
+        self.mont_form.neg_assign();
+    }
+
+    /// Doubles this element in place with a single modular addition
+    pub fn double_in_place(&mut self) {
+        let original = self.mont_form.clone();
+        self.mont_form = self.mont_form.add(&original);
+    }
+
+    /// Triples this element in place using two modular additions
+    pub fn triple_in_place(&mut self) {
+        let original = self.mont_form.clone();
+        self.double_in_place();
+        self.mont_form = self.mont_form.add(&original);
+    }
+
+    /// Multiplies this element by 8 in place using three doublings
+    pub fn mul8_in_place(&mut self) {
+        self.double_in_place();
+        self.double_in_place();
+        self.double_in_place();
+    }
+
+    /// Get the modulus of this field element
+    pub fn modulus(&self) -> BigUint {
+        self.mont_form.constants.modulus.clone()
+    }
+
+    /// Returns the underlying Montgomery representation
+    ///
+    /// The limbs are `value · R mod p` and may not be fully reduced; use
+    /// `from_montgomery` for the canonical value.
+    pub fn raw(&self) -> &MontgomeryForm {
+        &self.mont_form
+    }
+
+    /// Returns true if this element is the multiplicative identity of its own field
+    ///
+    /// One in Montgomery form is `R mod N` rather than the limbs `[1, 0, 0, 0]`, so
+    /// the reduced value is compared against the field's cached one.
+    pub fn is_one(&self) -> bool {
+        let cache = FieldCache::get(&self.mont_form.constants.modulus);
+        self.mont_form == cache.one
+    }
+
+    /// Panics unless both elements belong to the same field
+    fn assert_same_field(&self, other: &Self) {
+        assert!(
+            self.mont_form.constants.modulus == other.mont_form.constants.modulus,
+            "Field moduli must match"
+        );
     }
 }
 
 impl Field for Fp {
-    fn characteristic() -> Vec<u64> {
-        // TODO: Implement proper characteristic calculation
-        vec![1]
+    fn characteristic(&self) -> Vec<u64> {
+        to_limbs(&self.mont_form.constants.modulus, WORDS_PER_LIMB)
+    }
+
+    fn modulus_biguint(&self) -> BigUint {
+        self.mont_form.constants.modulus.clone()
     }
 
     fn inverse(&self) -> Option<Self> {
@@ -227,38 +742,34 @@ impl Field for Fp {
             return None;
         }
 
-        // Convert from Montgomery form
-        let mut a = self.mont_form.value.clone();
-        let modulus_limbs = to_limbs(&self.mont_form.constants.modulus, WORDS_PER_LIMB);
-        let one_limbs = vec![1u64; WORDS_PER_LIMB];
-        let n_prime_limbs = to_limbs(&self.mont_form.constants.n_prime, WORDS_PER_LIMB);
-
-        // Convert from Montgomery form
-        a = mont_mul(&a, &one_limbs, &modulus_limbs, &n_prime_limbs);
-
         // Convert to BigUint for inverse calculation
-        let a_biguint = BigUint::from_bytes_le(&to_bytes(&a));
+        let a_biguint = self.from_montgomery();
         let modulus = self.mont_form.constants.modulus.clone();
 
         // Implementation of modular inverse using extended Euclidean algorithm
         let (g, mut x) = mod_inverse(&a_biguint, &modulus);
         
-        // Check if gcd is 1 (meaning inverse exists)
+        // With a composite modulus, elements sharing a factor with it have no inverse
         if g != BigUint::one() {
             return None;
         }
         
         // Ensure x is reduced modulo the modulus
-        x = x % &modulus;
+        x %= &modulus;
+
+        // With gcd 1 the Bezout coefficient is the inverse
+        debug_assert_eq!((&a_biguint * &x) % &modulus, BigUint::one());
 
         // Convert back to Montgomery form
-        let mut result = Self::new(x, modulus);
-        result.mont_form.reduce();
-        Some(result)
+        Some(Self::new(x, modulus))
+    }
+
+    fn mul_add(&self, b: &Self, c: &Self) -> Self {
+        Fp::mul_add(self, b, c)
     }
 
     fn pow(&self, exp: u64) -> Self {
-        let mut result = Self::one();
+        let mut result = self.one_like();
         let mut base = self.clone();
         let mut exp = exp;
 
@@ -266,18 +777,29 @@ impl Field for Fp {
             if exp % 2 == 1 {
                 result = result * base.clone();
             }
-            base = base.square();
+            base = Field::square(&base);
             exp /= 2;
         }
 
         result
     }
+
+    fn mul_u64(&self, k: u64) -> Self {
+        Self { mont_form: self.mont_form.mul_u64(k) }
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::zero_with(self.modulus())
+    }
+
+    fn one_like(&self) -> Self {
+        Self::one_with(self.modulus())
+    }
 }
 
 impl PrimeField for Fp {
-    fn modulus() -> Vec<u64> {
-        // TODO: Return proper modulus representation
-        vec![1]
+    fn modulus(&self) -> Vec<u64> {
+        self.characteristic()
     }
 
     fn to_montgomery(&self) -> Self {
@@ -295,6 +817,7 @@ impl Add for Fp {
     type Output = Self;
 
     fn add(mut self, other: Self) -> Self {
+        self.assert_same_field(&other);
         self.mont_form = self.mont_form.add(&other.mont_form);
         self
     }
@@ -304,6 +827,7 @@ impl Sub for Fp {
     type Output = Self;
 
     fn sub(mut self, other: Self) -> Self {
+        self.assert_same_field(&other);
         self.mont_form = self.mont_form.sub(&other.mont_form);
         self
     }
@@ -313,6 +837,7 @@ impl Mul for Fp {
     type Output = Self;
 
     fn mul(mut self, other: Self) -> Self {
+        self.assert_same_field(&other);
         self.mont_form = self.mont_form.mul(&other.mont_form);
         self
     }
@@ -320,45 +845,188 @@ impl Mul for Fp {
 
 impl Zero for Fp {
     fn zero() -> Self {
-        let modulus = BigUint::from_str(
-            "21888242871839275222246405745257275088696311157297823662689037894645226208583"
-        ).unwrap();
-        Self::new(BigUint::zero(), modulus)
+        Self::new(BigUint::zero(), DEFAULT_FIELD_MODULUS.clone())
     }
 
     fn is_zero(&self) -> bool {
-        self.mont_form.value.iter().all(|&x| x == 0)
+        // A lazily reduced zero can hold the modulus itself, so reduce first. OR-ing
+        // the limbs avoids an early exit on the first non-zero limb.
+        let is_zero_limbs = |limbs: &[u64]| limbs.iter().fold(0u64, |acc, &limb| acc | limb) == 0;
+        if self.mont_form.extra_precision == 0 {
+            return is_zero_limbs(&self.mont_form.value);
+        }
+        let mut reduced = self.mont_form.clone();
+        reduced.reduce();
+        is_zero_limbs(&reduced.value)
     }
 }
 
 impl One for Fp {
     fn one() -> Self {
-        let modulus = BigUint::from_str(
-            "21888242871839275222246405745257275088696311157297823662689037894645226208583"
-        ).unwrap();
-        Self::new(BigUint::one(), modulus)
+        Self::new(BigUint::one(), DEFAULT_FIELD_MODULUS.clone())
+    }
+
+    fn is_one(&self) -> bool {
+        Fp::is_one(self)
     }
 }
 
-impl Neg for Fp {
-    type Output = Self;
+impl Fp {
+    /// Adds the Montgomery limbs of every element into a wider accumulator and
+    /// reduces modulo `p` once
+    ///
+    /// Montgomery representatives add like the values they encode, and each is below
+    /// `2^256`, so the extra carry word absorbs up to `2^64` terms. An empty iterator
+    /// has no field to take, so it gives the BN254 `Zero::zero()`.
+    fn sum_unreduced<T: Borrow<Fp>, I: Iterator<Item = T>>(mut iter: I) -> Self {
+        let first = match iter.next() {
+            Some(first) => first.borrow().clone(),
+            None => return Self::zero(),
+        };
 
-    fn neg(mut self) -> Self {
-        let mut zero = Self::zero();
-        self.mont_form = zero.mont_form.sub(&self.mont_form);
-        self
+        let mut acc = [0u64; WORDS_PER_LIMB + 1];
+        let mut accumulate = |x: &Fp| {
+            first.assert_same_field(x);
+            let mut carry = 0u128;
+            for (i, slot) in acc.iter_mut().enumerate() {
+                let limb = x.mont_form.value.get(i).copied().unwrap_or(0);
+                let total = *slot as u128 + limb as u128 + carry;
+                *slot = total as u64;
+                carry = total >> 64;
+            }
+        };
+        accumulate(&first);
+        for x in iter {
+            accumulate(x.borrow());
+        }
+
+        let constants = first.mont_form.constants;
+        let total = BigUint::from_bytes_le(&to_bytes(&acc)) % &constants.modulus;
+        Self {
+            mont_form: MontgomeryForm {
+                value: to_limbs(&total, WORDS_PER_LIMB),
+                extra_precision: 0,
+                constants,
+            },
+        }
     }
 }
 
-impl Div for Fp {
-    type Output = Self;
+impl Sum for Fp {
+    /// Sums the elements with a single final reduction, see `sum_unreduced`
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::sum_unreduced(iter)
+    }
+}
 
+impl<'a> Sum<&'a Fp> for Fp {
+    fn sum<I: Iterator<Item = &'a Fp>>(iter: I) -> Self {
+        Self::sum_unreduced(iter)
+    }
+}
+
+impl Product for Fp {
+    /// Multiplies the elements, leaving the running product lazily reduced until the end
+    ///
+    /// An empty iterator has no field to take, so it gives the BN254 `One::one()`.
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => {
+                let mut acc = iter.fold(first, |acc, x| acc * x);
+                acc.mont_form.reduce();
+                acc
+            }
+            None => Self::one(),
+        }
+    }
+}
+
+impl<'a> Product<&'a Fp> for Fp {
+    fn product<I: Iterator<Item = &'a Fp>>(iter: I) -> Self {
+        iter.cloned().product()
+    }
+}
+
+impl Neg for Fp {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero_with(self.modulus()) - self
+    }
+}
+
+impl Div for Fp {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self {
         let inv = rhs.inverse().expect("Division by zero");
         self * inv
     }
 }
 
+/// Fixed-base exponentiation with cached squarings `base^(2^i)`
+///
+/// Each `exp` then costs one multiplication per set exponent bit and no squarings.
+/// Exponents wider than the table keep squaring from its last entry.
+#[derive(Debug, Clone)]
+pub struct FixedBaseExp {
+    /// `powers[i] = base^(2^i)`
+    powers: Vec<Fp>,
+}
+
+impl FixedBaseExp {
+    /// Precomputes `base^(2^i)` for `i < max_bits`
+    pub fn new(base: &Fp, max_bits: usize) -> Self {
+        let mut powers = Vec::with_capacity(max_bits.max(1));
+        powers.push(base.clone());
+        for i in 1..max_bits {
+            powers.push(Field::square(&powers[i - 1]));
+        }
+        Self { powers }
+    }
+
+    /// Raises the base to `e`
+    pub fn exp(&self, e: &BigUint) -> Fp {
+        let mut result = self.powers[0].one_like();
+        let mut extra = self.powers[self.powers.len() - 1].clone();
+        for i in 0..e.bits() as usize {
+            let power = match self.powers.get(i) {
+                Some(power) => power,
+                None => {
+                    extra = Field::square(&extra);
+                    &extra
+                }
+            };
+            if e.bit(i as u64) {
+                result = result * power.clone();
+            }
+        }
+        result
+    }
+}
+
+/// Recodes `exp` in balanced ternary, least significant digit first
+///
+/// Every digit is `-1`, `0` or `1` and `exp = sum(digits[i] * 3^i)`. Zero recodes
+/// to an empty vector.
+pub fn recode_ternary(exp: &BigUint) -> Vec<i8> {
+    let mut digits = Vec::new();
+    let mut remaining = exp.clone();
+    while !remaining.is_zero() {
+        let digit = (&remaining % 3u32).to_u8().expect("remainder below 3");
+        remaining /= 3u32;
+        if digit == 2 {
+            // 2 = 3 - 1: emit -1 and carry into the next digit
+            digits.push(-1);
+            remaining += 1u32;
+        } else {
+            digits.push(digit as i8);
+        }
+    }
+    digits
+}
+
 // Helper function for modular inverse using extended Euclidean algorithm for BigUint
 fn mod_inverse(a: &BigUint, m: &BigUint) -> (BigUint, BigUint) {
     let mut s = BigUint::zero();
@@ -412,7 +1080,6 @@ fn modular_sub(a: &BigUint, b: &BigUint, m: &BigUint) -> BigUint {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
     
     #[test]
     fn test_field_creation() {
@@ -426,6 +1093,446 @@ mod tests {
         assert_eq!(a.from_montgomery(), BigUint::from(5u32));
     }
     
+    #[test]
+    fn test_debug_shows_canonical_value() {
+        let modulus = BigUint::from(17u32);
+        let a = Fp::new(BigUint::from(5u32), modulus.clone());
+        
+        let debug = format!("{:?}", a);
+        assert!(debug.contains("5"));
+        assert!(debug.contains("17"));
+        
+        // Lazily reduced products print the same as freshly created values
+        let b = Fp::new(BigUint::from(3u32), modulus.clone()) * Fp::new(BigUint::from(13u32), modulus.clone());
+        assert_eq!(format!("{:?}", b), format!("{:?}", Fp::new(BigUint::from(5u32), modulus)));
+        assert_eq!(a.raw().constants.modulus, BigUint::from(17u32));
+    }
+    
+    #[test]
+    fn test_sum_and_product() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let values: Vec<Fp> = (1..=10u64).map(|i| Fp::from_u64(i * 1_000_003, modulus.clone())).collect();
+        
+        let sum_fold = values.iter().fold(Fp::zero_with(modulus.clone()), |acc, x| acc + x.clone());
+        assert_eq!(values.iter().sum::<Fp>(), sum_fold);
+        assert_eq!(values.clone().into_iter().sum::<Fp>(), sum_fold);
+        
+        // Near-maximal and lazily reduced terms overflow 256 bits before the final reduction
+        let large: Vec<Fp> = (1..=64u64)
+            .map(|i| Fp::new(&modulus - i, modulus.clone()) * Fp::new(&modulus - 2u32, modulus.clone()))
+            .collect();
+        let large_fold = large.iter().fold(Fp::zero_with(modulus.clone()), |acc, x| acc + x.clone());
+        assert_eq!(large.iter().sum::<Fp>(), large_fold);
+        
+        let product_fold = values.iter().fold(Fp::from_u64(1, modulus.clone()), |acc, x| acc * x.clone());
+        assert_eq!(values.iter().product::<Fp>(), product_fold);
+        assert_eq!(values.clone().into_iter().product::<Fp>(), product_fold);
+        
+        // Small fields stay in their own field
+        let small = BigUint::from(17u32);
+        let elements: Vec<Fp> = (1..=5u64).map(|i| Fp::from_u64(i, small.clone())).collect();
+        assert_eq!(elements.iter().sum::<Fp>(), Fp::from_u64(15, small.clone()));
+        assert_eq!(elements.iter().product::<Fp>(), Fp::from_u64(120 % 17, small.clone()));
+        assert_eq!(elements.iter().sum::<Fp>().modulus(), small);
+        
+        // Empty iterators give the identities
+        assert!(Vec::<Fp>::new().into_iter().sum::<Fp>().is_zero());
+        assert_eq!(Vec::<Fp>::new().into_iter().product::<Fp>(), Fp::one());
+    }
+    
+    #[test]
+    fn test_inverse_with_composite_modulus() {
+        let modulus = BigUint::from(15u32);
+        let element = |v: u64| Fp::from_u64(v, modulus.clone());
+        
+        assert_eq!(element(2).inverse(), Some(element(8)));
+        assert_eq!(element(14).inverse(), Some(element(14)));
+        
+        // Elements sharing a factor with 15 have no inverse
+        for v in [0, 3, 5, 6, 9, 10, 12] {
+            assert_eq!(element(v).inverse(), None, "{} should not be invertible", v);
+        }
+        
+        // Every unit times its inverse is one
+        for v in [1, 2, 4, 7, 8, 11, 13, 14] {
+            let inv = element(v).inverse().unwrap();
+            assert_eq!(element(v) * inv, element(1));
+        }
+    }
+    
+    #[test]
+    fn test_fixed_base_exp() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let base = Fp::from_u64(0x1234_5678_9abc, modulus.clone());
+        let table = FixedBaseExp::new(&base, 254);
+        
+        let exponents = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(0xdead_beefu64),
+            &modulus - 2u32,
+            // Wider than the table
+            BigUint::from(1u32) << 300,
+        ];
+        for e in &exponents {
+            assert_eq!(table.exp(e), base.pow_biguint(e));
+        }
+        
+        // A short table extends on demand
+        let short = FixedBaseExp::new(&base, 4);
+        assert_eq!(short.exp(&exponents[3]), base.pow_biguint(&exponents[3]));
+    }
+    
+    #[test]
+    fn test_is_zero_ignores_reduction_state() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        
+        // Zero represented by the unreduced limbs of the modulus itself
+        let mut lazy_zero = Fp::zero_with(modulus.clone());
+        lazy_zero.mont_form.value = to_limbs(&modulus, WORDS_PER_LIMB);
+        lazy_zero.mont_form.extra_precision = 1;
+        assert!(lazy_zero.is_zero());
+        assert_eq!(lazy_zero, Fp::zero_with(modulus.clone()));
+        
+        // Zero reached through lazily reduced products
+        let a = Fp::from_u64(123456789, modulus.clone());
+        let product = a.clone() * a.clone() * a.clone();
+        assert!((product.clone() - product.clone()).is_zero());
+        assert!((product.clone() * Fp::zero_with(modulus.clone())).is_zero());
+        assert!(!product.is_zero());
+    }
+    
+    #[test]
+    fn test_is_one() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        assert!(Fp::from_u64(1, modulus.clone()).is_one());
+        assert!(Fp::from_u64(1, BigUint::from(17u32)).is_one());
+        assert!(!Fp::from_u64(2, modulus.clone()).is_one());
+        assert!(!Fp::zero_with(modulus.clone()).is_one());
+        
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+            let x = Fp::new(BigUint::from_bytes_le(&bytes), modulus.clone());
+            if x.is_zero() {
+                continue;
+            }
+            // The product is lazily reduced
+            let product = x.clone() * x.inverse().unwrap();
+            assert!(product.is_one());
+        }
+    }
+    
+    #[test]
+    fn test_mul_add() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let a = Fp::new(&modulus - 3u32, modulus.clone());
+        let b = Fp::new(&modulus - 5u32, modulus.clone());
+        let c = Fp::new(&modulus - 7u32, modulus.clone());
+        
+        assert_eq!(a.mul_add(&b, &c), a.clone() * b.clone() + c.clone());
+        assert_eq!(a.mul_add(&b, &c).from_montgomery(), BigUint::from(8u32));
+        
+        // Identities taken from the operands' field
+        assert_eq!(a.one_like().mul_add(&b, &c), b.clone() + c.clone());
+        assert_eq!(a.mul_add(&b, &a.zero_like()), a * b);
+        
+        // Exhaustive over a small field, including lazily reduced inputs
+        let small = BigUint::from(17u32);
+        let elements: Vec<Fp> = (0..17u64).map(|v| Fp::from_u64(v, small.clone())).collect();
+        for x in &elements {
+            for y in &elements {
+                let lazy = x.clone() * y.clone();
+                let c = &elements[16];
+                assert_eq!(x.mul_add(y, c), x.clone() * y.clone() + c.clone());
+                assert_eq!(lazy.mul_add(y, &lazy), lazy.clone() * y.clone() + lazy.clone());
+            }
+        }
+    }
+    
+    #[test]
+    fn test_inverse_or_zero() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let zero = Fp::zero_with(modulus.clone());
+        assert_eq!(zero.inverse_or_zero(), zero);
+        assert_eq!(zero.inverse_or_zero().modulus(), modulus);
+        
+        for value in [1u64, 2, 123456789] {
+            let x = Fp::from_u64(value, modulus.clone());
+            assert_eq!(x.inverse_or_zero(), x.inverse().unwrap());
+        }
+        let x = Fp::new(&modulus - 1u32, modulus.clone());
+        assert_eq!(x.inverse_or_zero(), x.inverse().unwrap());
+    }
+    
+    #[test]
+    #[cfg(feature = "prover")]
+    fn test_from_random_bytes_is_uniform() {
+        use rand::SeedableRng;
+        
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1744);
+        
+        // Eight equal-width ranges of [0, p); plain reduction of 254 random bits
+        // would land in the lowest three about twice as often as the rest
+        const BUCKETS: usize = 8;
+        const SAMPLES: usize = 4000;
+        let mut counts = [0usize; BUCKETS];
+        for _ in 0..SAMPLES {
+            let value = Fp::from_random_bytes(&mut rng, &modulus).from_montgomery();
+            assert!(value < modulus);
+            let bucket = (value * BUCKETS) / &modulus;
+            counts[bucket.to_usize().unwrap()] += 1;
+        }
+        
+        // Chi-squared with 7 degrees of freedom; 24.3 is the 0.1% critical value
+        let expected = (SAMPLES / BUCKETS) as f64;
+        let chi_squared: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi_squared < 24.3, "counts {:?} give chi-squared {}", counts, chi_squared);
+        
+        // Small moduli mask the top byte down to the modulus width
+        let small = BigUint::from(17u32);
+        for _ in 0..100 {
+            assert!(Fp::from_random_bytes(&mut rng, &small).from_montgomery() < small);
+        }
+    }
+    
+    #[test]
+    fn test_canonical_limbs_match_biguint_conversion() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let values = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(u64::MAX),
+            &modulus - 1u32,
+            &modulus >> 3usize,
+        ];
+        
+        for value in values {
+            let a = Fp::new(value.clone(), modulus.clone());
+            let limbs = a.to_canonical_limbs();
+            assert_eq!(BigUint::from_bytes_le(&to_bytes(&limbs)), value);
+            assert_eq!(a.from_montgomery(), value);
+            assert_eq!(BigUint::from_bytes_be(&a.to_bytes_be()), value);
+            
+            // A lazily reduced representative (N < 2^254, so value + N still fits)
+            // converts to the same canonical value
+            let mut lazy = a.clone();
+            let shifted = BigUint::from_bytes_le(&to_bytes(&lazy.mont_form.value)) + &modulus;
+            lazy.mont_form.value = to_limbs(&shifted, WORDS_PER_LIMB);
+            lazy.mont_form.extra_precision = 1;
+            assert_eq!(lazy.to_canonical_limbs(), limbs);
+        }
+    }
+    
+    #[test]
+    fn test_batch_bytes_round_trip() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let elems: Vec<Fp> = (0..100u64)
+            .map(|i| Fp::new(&modulus - BigUint::from(i * i * 7919 + 1), modulus.clone()))
+            .collect();
+        
+        let bytes = Fp::batch_to_bytes(&elems);
+        assert_eq!(bytes.len(), FIELD_ELEMENT_BYTES * elems.len());
+        assert_eq!(Fp::batch_from_bytes(&bytes, &modulus).unwrap(), elems);
+        
+        assert!(Fp::batch_to_bytes(&[]).is_empty());
+    }
+    
+    #[test]
+    fn test_single_element_bytes_round_trip() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let a = Fp::new(BigUint::from(0x0102030405060708u64), modulus.clone());
+        
+        let mut expected_be = [0u8; FIELD_ELEMENT_BYTES];
+        expected_be[24..].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(a.to_bytes_be(), expected_be);
+        let mut expected_le = expected_be;
+        expected_le.reverse();
+        assert_eq!(a.to_bytes_le(), expected_le);
+        
+        // A lazily reduced product encodes the same as its canonical copy
+        let x = Fp::new(&modulus - 3u32, modulus.clone());
+        let product = x.clone() * x;
+        let canonical = Fp::new(product.from_montgomery(), modulus.clone());
+        assert_eq!(product.to_bytes_be(), canonical.to_bytes_be());
+        assert_eq!(product.to_bytes_le(), canonical.to_bytes_le());
+        
+        for value in [a, product, Fp::new(&modulus - 1u32, modulus.clone())] {
+            assert_eq!(Fp::from_bytes_be(&value.to_bytes_be(), modulus.clone()).unwrap(), value);
+            assert_eq!(Fp::from_bytes_le(&value.to_bytes_le(), modulus.clone()).unwrap(), value);
+        }
+    }
+    
+    #[test]
+    fn test_from_bytes_rejects_non_canonical() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let encode_be = |value: &BigUint| {
+            let bytes = value.to_bytes_be();
+            let mut out = [0u8; FIELD_ELEMENT_BYTES];
+            out[FIELD_ELEMENT_BYTES - bytes.len()..].copy_from_slice(&bytes);
+            out
+        };
+        
+        // p - 1 is the largest accepted value, p itself is rejected rather than reduced to zero
+        let max = Fp::from_bytes_be(&encode_be(&(&modulus - 1u32)), modulus.clone()).unwrap();
+        assert_eq!(max.from_montgomery(), &modulus - 1u32);
+        let modulus_bits = modulus.bits();
+        assert_eq!(
+            Fp::from_bytes_be(&encode_be(&modulus), modulus.clone()),
+            Err(FieldError::OutOfRange { got_bits: modulus_bits, modulus_bits })
+        );
+        assert_eq!(
+            Fp::from_bytes_be(&[0xff; FIELD_ELEMENT_BYTES], modulus.clone()),
+            Err(FieldError::OutOfRange { got_bits: 256, modulus_bits })
+        );
+        
+        let mut modulus_le = encode_be(&modulus);
+        modulus_le.reverse();
+        assert_eq!(
+            Fp::from_bytes_le(&modulus_le, modulus.clone()),
+            Err(FieldError::OutOfRange { got_bits: modulus_bits, modulus_bits })
+        );
+    }
+    
+    #[test]
+    fn test_ternary_recoding_and_pow() {
+        let three = BigUint::from(3u32);
+        let exponents = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            BigUint::from(8u32),
+            BigUint::from(123456789u64),
+            DEFAULT_FIELD_MODULUS.clone() - 2u32,
+        ];
+        
+        for exp in &exponents {
+            let digits = recode_ternary(exp);
+            assert!(digits.iter().all(|d| (-1..=1).contains(d)));
+            assert_ne!(digits.last(), Some(&0));
+            
+            // Reconstruct from the most significant digit down
+            let mut value = num_bigint::BigInt::from(0);
+            for &digit in digits.iter().rev() {
+                value = value * 3 + digit;
+            }
+            assert_eq!(value.to_biguint().unwrap(), *exp);
+        }
+        assert_eq!(recode_ternary(&BigUint::from(8u32)), vec![-1, 0, 1]);
+        
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        let base = Fp::new(BigUint::from(987654321u64), modulus.clone());
+        for exp in &exponents {
+            assert_eq!(base.pow_ternary(exp), base.pow_biguint(exp));
+        }
+        
+        let zero = Fp::zero_with(modulus);
+        assert_eq!(zero.pow_ternary(&three), zero);
+        assert!(zero.pow_ternary(&BigUint::from(0u32)).is_one());
+    }
+    
+    #[test]
+    fn test_characteristic_and_modulus_limbs() {
+        let expected = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        
+        let limbs = Fp::one().characteristic();
+        assert_eq!(limbs.len(), WORDS_PER_LIMB);
+        let reconstructed = limbs
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, &limb| (acc << 64) + BigUint::from(limb));
+        assert_eq!(reconstructed, expected);
+        
+        assert_eq!(<Fp as PrimeField>::modulus(&Fp::one()), limbs);
+        assert_eq!(Fp::one().modulus(), expected);
+        
+        let small = Fp::from_u64(3, BigUint::from(17u32));
+        assert_eq!(small.characteristic(), vec![17, 0, 0, 0]);
+        assert_eq!(<Fp as PrimeField>::modulus(&small), vec![17, 0, 0, 0]);
+    }
+    
+    #[test]
+    fn test_modulus_biguint_from_generic_code() {
+        fn field_modulus<F: Field>(element: &F) -> BigUint {
+            element.modulus_biguint()
+        }
+        
+        let expected = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        assert_eq!(Fp::one().modulus_biguint(), expected);
+        assert_eq!(field_modulus(&Fp::one()), expected);
+        
+        let scalar = Scalar::new(BigUint::from(5u32));
+        assert_eq!(scalar.as_fp().modulus_biguint(), Scalar::modulus());
+        assert_eq!(field_modulus(scalar.as_fp()), Scalar::modulus());
+        assert_eq!(field_modulus(&Fp::from_u64(3, BigUint::from(17u32))), BigUint::from(17u32));
+    }
+    
+    #[test]
+    fn test_legendre_matches_euler_criterion() {
+        for modulus in [BigUint::from(13u32), BigUint::from(29u32), BigUint::from_str(DEFAULT_MODULUS).unwrap()] {
+            let p_minus_one = &modulus - 1u32;
+            let mut rng = rand::thread_rng();
+            for _ in 0..50 {
+                let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+                let a = Fp::new(BigUint::from_bytes_le(&bytes), modulus.clone());
+
+                let euler = a.pow_biguint(&(&p_minus_one >> 1));
+                let expected = if a.is_zero() { 0 } else if euler.is_one() { 1 } else { -1 };
+                assert_eq!(a.legendre(), expected);
+
+                // Non-residues are rejected and residues still get a correct root
+                match a.sqrt() {
+                    Some(root) => {
+                        assert_ne!(expected, -1);
+                        assert_eq!(Field::square(&root), a);
+                    }
+                    None => assert_eq!(expected, -1),
+                }
+            }
+            assert_eq!(Fp::new(BigUint::zero(), modulus.clone()).legendre(), 0);
+        }
+    }
+    
+    #[test]
+    fn test_conversion_errors_carry_context() {
+        let modulus = BigUint::from(1000003u32);
+        let mut bytes = [0u8; FIELD_ELEMENT_BYTES];
+        bytes[FIELD_ELEMENT_BYTES - 4..].copy_from_slice(&(1u32 << 27).to_be_bytes());
+        
+        let err = Fp::from_bytes_be(&bytes, modulus.clone()).unwrap_err();
+        assert_eq!(err, FieldError::OutOfRange { got_bits: 28, modulus_bits: 20 });
+        assert_eq!(err.to_string(), "element out of range: a 28-bit value is not below the 20-bit modulus");
+        
+        let mut blob = Fp::from_u64(5, modulus.clone()).to_bytes_be().to_vec();
+        blob.extend_from_slice(&bytes);
+        let err = Fp::batch_from_bytes(&blob, &modulus).unwrap_err();
+        assert_eq!(err, FieldError::NonCanonical { index: 1, got_bits: 28 });
+        assert_eq!(err.to_string(), "element 1 is not canonical: a 28-bit value is not below the modulus");
+    }
+    
+    #[test]
+    fn test_elements_of_different_fields_are_distinct() {
+        let (p17, p19) = (BigUint::from(17u32), BigUint::from(19u32));
+        assert_ne!(Fp::from_u64(0, p17.clone()), Fp::from_u64(0, p19.clone()));
+        assert_ne!(Fp::from_u64(1, p17.clone()), Fp::one());
+        assert_ne!(Fp::from_u64(5, p17.clone()), Fp::from_u64(5, p19.clone()));
+        
+        let x = Fp::from_u64(5, p17.clone());
+        assert_eq!(x.zero_like(), Fp::zero_with(p17.clone()));
+        assert_eq!(x.one_like(), Fp::one_with(p17.clone()));
+        assert_eq!(x.pow(0), Fp::one_with(p17));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Field moduli must match")]
+    fn test_mixing_fields_panics() {
+        let _ = Fp::from_u64(1, BigUint::from(17u32)) + Fp::zero();
+    }
+    
     #[test]
     fn test_field_addition() {
         // Use a small prime field for testing
@@ -494,4 +1601,330 @@ mod tests {
         let prod = a.clone() * a_inv;
         assert_eq!(prod.from_montgomery(), BigUint::from(1u32));
     }
+    
+    #[test]
+    fn test_field_negation() {
+        // Use a small prime field for testing
+        let modulus = BigUint::from(17u32);
+        
+        let a = Fp::new(BigUint::from(5u32), modulus.clone());
+        let neg_a = -a.clone();
+        
+        // -5 mod 17 = 12, and the result stays in the mod-17 field
+        assert_eq!(neg_a.from_montgomery(), BigUint::from(12u32));
+        assert_eq!(neg_a.modulus(), modulus);
+        
+        // x + (-x) = 0
+        let sum = a + neg_a;
+        assert_eq!(sum, Fp::zero_with(modulus.clone()));
+        
+        // -0 = 0
+        let zero = Fp::zero_with(modulus);
+        assert_eq!(-zero.clone(), zero);
+    }
+    
+    #[test]
+    fn test_pow_fixed_window_matches_pow_biguint() {
+        use rand::Rng;
+        
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let base = Fp::new(BigUint::from(7u32), modulus.clone());
+        let mut rng = rand::thread_rng();
+        
+        for _ in 0..5 {
+            let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+            let exp = BigUint::from_bytes_le(&bytes) % &modulus;
+            
+            let expected = base.pow_biguint(&exp);
+            assert_eq!(expected.from_montgomery(), BigUint::from(7u32).modpow(&exp, &modulus));
+            
+            for window in [1, 4, 5] {
+                assert_eq!(base.pow_fixed_window(&exp, window), expected);
+            }
+        }
+        
+        // Small field and trivial exponents
+        let small = Fp::new(BigUint::from(3u32), BigUint::from(17u32));
+        assert_eq!(small.pow_fixed_window(&BigUint::from(0u32), 4).from_montgomery(), BigUint::from(1u32));
+        assert_eq!(small.pow_fixed_window(&BigUint::from(16u32), 3).from_montgomery(), BigUint::from(1u32));
+        assert_eq!(small.pow_fixed_window(&BigUint::from(5u32), 2).from_montgomery(), BigUint::from(5u32)); // 3^5 = 243 = 5 mod 17
+    }
+    
+    #[test]
+    fn test_from_str_radix() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        
+        let dec = Fp::from_str_radix("1234567890123456789", 10, modulus.clone()).unwrap();
+        let hex = Fp::from_str_radix("112210f47de98115", 16, modulus.clone()).unwrap();
+        let bin = Fp::from_str_radix(
+            "1000100100010000100001111010001111101111010011000000100010101",
+            2,
+            modulus.clone(),
+        ).unwrap();
+        assert_eq!(dec, hex);
+        assert_eq!(dec, bin);
+        
+        // Values above the modulus are reduced
+        let small = BigUint::from(17u32);
+        assert_eq!(Fp::from_str_radix("z", 36, small.clone()).unwrap().from_montgomery(), BigUint::from(1u32));
+        
+        assert_eq!(Fp::from_str_radix("12g", 16, small.clone()), Err(FieldError::InvalidDigits { radix: 16 }));
+        assert_eq!(Fp::from_str_radix("", 10, small.clone()), Err(FieldError::InvalidDigits { radix: 10 }));
+        assert_eq!(Fp::from_str_radix("1", 37, small), Err(FieldError::InvalidRadix(37)));
+    }
+    
+    #[test]
+    fn test_u64_array_round_trip() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        
+        let five = Fp::from_u64(5, modulus.clone());
+        assert_eq!(five.to_u64_array(), [5, 0, 0, 0]);
+        
+        let limbs = [0x0123456789abcdef, 0xfedcba9876543210, 0x1111111111111111, 0x0222222222222222];
+        let a = Fp::from_u64_array(limbs, modulus.clone());
+        assert_eq!(a.to_u64_array(), limbs);
+        assert_eq!(Fp::from_u64_array(a.to_u64_array(), modulus.clone()), a);
+        
+        // Values at or above the modulus are reduced
+        let p_limbs = to_limbs(&modulus, WORDS_PER_LIMB);
+        let p = Fp::from_u64_array([p_limbs[0], p_limbs[1], p_limbs[2], p_limbs[3]], modulus.clone());
+        assert_eq!(p.to_u64_array(), [0, 0, 0, 0]);
+        
+        let max = Fp::from_u64_array([u64::MAX; 4], modulus.clone());
+        let expected = ((BigUint::from(1u32) << 256) - 1u32) % &modulus;
+        assert_eq!(max.from_montgomery(), expected);
+    }
+    
+    #[test]
+    fn test_neg_assign_matches_neg() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        
+        for value in [0u64, 1, 5, 123456789] {
+            let a = Fp::from_u64(value, modulus.clone());
+            let mut b = a.clone();
+            b.neg_assign();
+            assert_eq!(b, -a.clone());
+            
+            // Negating twice is the identity
+            b.neg_assign();
+            assert_eq!(b, a);
+        }
+        
+        // Lazily reduced products are negated correctly as well
+        let x = Fp::from_u64(7, BigUint::from(17u32)) * Fp::from_u64(9, BigUint::from(17u32));
+        let mut y = x.clone();
+        y.neg_assign();
+        assert_eq!(y.from_montgomery(), BigUint::from(5u32)); // 63 = 12 mod 17, -12 = 5
+        assert!((x + y).is_zero());
+    }
+    
+    #[test]
+    fn test_mul_u64_matches_full_multiplication() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let a = Fp::new(BigUint::from_str("1234567890123456789012345678901234567890").unwrap(), modulus.clone());
+        
+        for k in [0u64, 1, 2, 17, 1000, u32::MAX as u64, u64::MAX] {
+            assert_eq!(a.mul_u64(k), a.clone() * Fp::from_u64(k, modulus.clone()));
+        }
+        
+        // Small field, including the lazily reduced output of a multiplication
+        let small = Fp::from_u64(7, BigUint::from(17u32)) * Fp::from_u64(9, BigUint::from(17u32));
+        assert_eq!(small.mul_u64(5).from_montgomery(), BigUint::from(9u32)); // 63 * 5 = 315 = 9 mod 17
+    }
+    
+    #[test]
+    fn test_batch_from_bytes() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let encode = |value: &BigUint| {
+            let bytes = value.to_bytes_be();
+            let mut out = vec![0u8; FIELD_ELEMENT_BYTES - bytes.len()];
+            out.extend_from_slice(&bytes);
+            out
+        };
+        
+        let values = [BigUint::from(1u32), BigUint::from(123456789u64), &modulus - 1u32];
+        let mut blob: Vec<u8> = values.iter().flat_map(&encode).collect();
+        
+        let decoded = Fp::batch_from_bytes(&blob, &modulus).unwrap();
+        assert_eq!(decoded.len(), 3);
+        for (fp, value) in decoded.iter().zip(values.iter()) {
+            assert_eq!(fp.from_montgomery(), *value);
+            assert_eq!(fp.to_bytes_be().to_vec(), encode(value));
+        }
+        
+        // A fourth chunk equal to the modulus is rejected with its index
+        blob.extend_from_slice(&encode(&modulus));
+        assert_eq!(Fp::batch_from_bytes(&blob, &modulus), Err(FieldError::NonCanonical { index: 3, got_bits: 254 }));
+        
+        // Truncated input
+        assert_eq!(Fp::batch_from_bytes(&blob[..40], &modulus), Err(FieldError::InvalidLength(40)));
+    }
+    
+    #[test]
+    fn test_hash_consistent_with_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        
+        fn hash_of(value: &Fp) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let a = Fp::new(BigUint::from(123456789u64), modulus.clone());
+        let b = Fp::new(BigUint::from(987654321u64), modulus.clone());
+        
+        // A lazily accumulated product equals (and hashes like) the direct value
+        let lazy = a.clone() * b.clone() * a.clone() * b.clone();
+        let direct = Fp::new(BigUint::from(123456789u64 * 987654321u64).pow(2), modulus.clone());
+        assert_eq!(lazy, direct);
+        assert_eq!(hash_of(&lazy), hash_of(&direct));
+        
+        // Equal values in different fields are distinct elements
+        let small_zero = Fp::new(BigUint::from(0u32), BigUint::from(17u32));
+        assert_ne!(Fp::zero(), small_zero);
+        assert_ne!(hash_of(&Fp::zero()), hash_of(&small_zero));
+        
+        let set: HashSet<Fp> = [lazy, direct, a.clone()].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+    }
+    
+    #[test]
+    fn test_small_constant_multipliers() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let near_top = Fp::new(&modulus - 3u32, modulus.clone());
+        
+        for a in [Fp::new(BigUint::from(123456789u64), modulus.clone()), near_top] {
+            let mut doubled = a.clone();
+            doubled.double_in_place();
+            assert_eq!(doubled, a.clone() + a.clone());
+            
+            let mut tripled = a.clone();
+            tripled.triple_in_place();
+            assert_eq!(tripled, a.clone() + a.clone() + a.clone());
+            
+            let mut times8 = a.clone();
+            times8.mul8_in_place();
+            let repeated = (0..7).fold(a.clone(), |acc, _| acc + a.clone());
+            assert_eq!(times8, repeated);
+        }
+    }
+    
+    #[test]
+    fn test_frobenius_is_identity() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let a = Fp::new(BigUint::from(123456789u64), modulus.clone());
+        
+        assert_eq!(a.frobenius(1), a);
+        assert_eq!(a.frobenius(1), a.pow_biguint(&modulus));
+        assert_eq!(a.frobenius(6), a);
+    }
+    
+    #[test]
+    fn test_sqrt_exp_chain() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let exp = (&modulus + 1u32) >> 2;
+        
+        // The chain encodes exactly (p + 1) / 4
+        let mut encoded = BigUint::from(SQRT_CHAIN[0].1);
+        for &(squarings, digit) in &SQRT_CHAIN[1..] {
+            encoded = (encoded << squarings) + digit;
+        }
+        encoded <<= SQRT_CHAIN_TRAILING_SQUARINGS;
+        assert_eq!(encoded, exp);
+        
+        for value in [1u64, 2, 5, 123456789] {
+            let a = Fp::new(BigUint::from(value), modulus.clone());
+            assert_eq!(a.sqrt_exp_chain(), a.pow_biguint(&exp));
+        }
+        let a = Fp::new(&modulus - 7u32, modulus.clone());
+        assert_eq!(a.sqrt_exp_chain(), a.pow_biguint(&exp));
+    }
+    
+    #[test]
+    fn test_sqrt_recovers_curve_coordinates() {
+        let modulus = DEFAULT_FIELD_MODULUS.clone();
+        // 2·G on BN254 G1, as used by the ecAdd/ecMul precompile test vectors
+        let x = Fp::from_str_radix("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3", 16, modulus.clone()).unwrap();
+        let y = Fp::from_str_radix("15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4", 16, modulus.clone()).unwrap();
+        
+        // x² has x as one of its two roots
+        let root = Field::square(&x).sqrt().unwrap();
+        assert!(root == x || root == -x.clone());
+        
+        // Decompression: y is a root of x³ + 3
+        let rhs = Field::square(&x) * x.clone() + Fp::from_u64(3, modulus);
+        let (r1, r2) = rhs.sqrt_both().unwrap();
+        assert!(r1 == y || r2 == y);
+    }
+    
+    #[test]
+    fn test_sqrt_canonical_root() {
+        // 17 ≡ 1 (mod 4) exercises Tonelli-Shanks, the BN254 prime the (p + 1) / 4 shortcut
+        for modulus in [BigUint::from(17u32), BigUint::from(13u32), BigUint::from_str(DEFAULT_MODULUS).unwrap()] {
+            for v in 0..13u64 {
+                let a = Fp::from_u64(v, modulus.clone());
+                let square = Field::square(&a);
+                
+                let (r1, r2) = square.sqrt_both().unwrap();
+                assert_eq!(Field::square(&r1), square);
+                assert!((r1.clone() + r2.clone()).is_zero());
+                assert!(r1.from_montgomery() <= r2.from_montgomery());
+                
+                // sqrt always returns the smaller root
+                assert_eq!(square.sqrt().unwrap(), r1);
+            }
+        }
+        
+        // 3 is a non-residue mod 17
+        assert!(Fp::from_u64(3, BigUint::from(17u32)).sqrt().is_none());
+        assert!(Fp::from_u64(3, BigUint::from(17u32)).sqrt_both().is_none());
+        
+        assert_eq!(Fp::from_u64(3, BigUint::from(17u32)).square_root_count(), 0);
+        assert_eq!(Fp::from_u64(0, BigUint::from(17u32)).square_root_count(), 1);
+        assert_eq!(Fp::from_u64(4, BigUint::from(17u32)).square_root_count(), 2);
+    }
+    
+    #[test]
+    fn test_sqrt_never_returns_a_non_root() {
+        let modulus = BigUint::from_str(DEFAULT_MODULUS).unwrap();
+        let half = (&modulus - 1u32) >> 1;
+        let is_residue = |a: &Fp| a.is_zero() || a.pow_biguint(&half).is_one();
+        
+        // Random elements plus the values just below the modulus, where -1 and many
+        // of its neighbours are non-residues
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fp> = (0..64)
+            .map(|_| {
+                let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+                Fp::new(BigUint::from_bytes_le(&bytes), modulus.clone())
+            })
+            .collect();
+        values.extend((1..=16u32).map(|k| Fp::new(&modulus - k, modulus.clone())));
+        
+        let mut non_residues = 0;
+        for a in values {
+            match a.sqrt() {
+                Some(root) => {
+                    assert!(is_residue(&a));
+                    assert_eq!(Field::square(&root), a);
+                }
+                None => {
+                    assert!(!is_residue(&a));
+                    non_residues += 1;
+                }
+            }
+        }
+        assert!(non_residues > 0);
+        
+        // Exhaustively in a Tonelli-Shanks field
+        let small = BigUint::from(17u32);
+        for v in 0..17u64 {
+            let a = Fp::from_u64(v, small.clone());
+            let expected = (0..17u64).any(|r| (r * r) % 17 == v);
+            assert_eq!(a.sqrt().is_some(), expected);
+        }
+    }
 } 
\ No newline at end of file
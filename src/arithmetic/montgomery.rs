@@ -1,6 +1,5 @@
 use num_bigint::BigUint;
 use std::cmp::{PartialEq, Eq};
-use num_traits::{One, Zero};
 
 #[cfg(feature = "simd")]
 use super::simd_avx512;
@@ -85,13 +84,8 @@ impl MontgomeryForm {
     pub fn new(mut value: Vec<u64>, constants: MontgomeryConstants) -> Self {
         // Ensure the value is properly reduced before conversion
         let modulus_limbs = to_limbs(&constants.modulus, value.len());
-        if !ct_lt(&value, &modulus_limbs) {
-            let mut borrow = 0i64;
-            for i in 0..value.len() {
-                let diff = (value[i] as i128) - (modulus_limbs[i] as i128) - (borrow as i128);
-                value[i] = diff as u64;
-                borrow = if diff < 0 { 1 } else { 0 };
-            }
+        while !ct_lt(&value, &modulus_limbs) {
+            sub_limbs(&mut value, &modulus_limbs);
         }
         
         // Convert to Montgomery form by multiplying by R^2 mod N
@@ -112,27 +106,22 @@ impl MontgomeryForm {
         }
     }
 
-    /// Performs lazy reduction if extra precision is too high
+    /// Brings a lazily reduced value back into the canonical range `[0, N)`
     pub fn reduce(&mut self) {
-        // Only reduce if the extra precision is beyond our threshold
-        if self.extra_precision > self.constants.extra_bits {
-            let n = to_limbs(&self.constants.modulus, self.value.len());
-            let n_prime = to_limbs(&self.constants.n_prime, self.value.len());
-            
-            // Use optimized SIMD implementation when available
-            #[cfg(all(feature = "simd", target_arch = "x86_64"))]
-            if self.value.len() == 8 && simd_avx512::has_avx512f() {
-                unsafe {
-                    self.value = simd_avx512::mont_reduce_avx512(&self.value, &n, &n_prime);
-                    self.extra_precision = 0;
-                    return;
-                }
-            }
-            
-            // Fallback to standard Montgomery reduction
-            self.value = mont_reduce(&self.value, &n, &n_prime);
-            self.extra_precision = 0;
+        let n = to_limbs(&self.constants.modulus, self.value.len());
+        while !ct_lt(&self.value, &n) {
+            sub_limbs(&mut self.value, &n);
         }
+        self.extra_precision = 0;
+    }
+
+    /// Returns a canonical copy of this value, reducing only if it carries lazy precision
+    fn reduced(&self) -> Self {
+        let mut result = self.clone();
+        if result.extra_precision > 0 {
+            result.reduce();
+        }
+        result
     }
 
     /// Multiplies two Montgomery values with lazy reduction
@@ -142,26 +131,30 @@ impl MontgomeryForm {
         #[cfg(all(feature = "simd", target_arch = "x86_64"))]
         if self.value.len() == 8 && simd_avx512::has_avx512f() {
             unsafe {
+                // The kernel expects operands below N and returns a canonical product
                 let result = simd_avx512::mont_mul_avx512(
-                    &self.value,
-                    &other.value,
+                    &self.reduced().value,
+                    &other.reduced().value,
                     &to_limbs(&self.constants.modulus, self.value.len()),
                     &to_limbs(&self.constants.n_prime, self.value.len())
                 );
                 
-                // Track extra precision for lazy reduction
                 return Self {
                     value: result,
                     constants: self.constants.clone(),
-                    extra_precision: self.extra_precision + other.extra_precision + 1
+                    extra_precision: 0
                 };
             }
         }
         
+        // Operands are brought into [0, N) so the lazy product stays below 2N
+        let a = self.reduced();
+        let b = other.reduced();
+        
         // Fallback to non-SIMD implementation with lazy reduction
         let result = mont_mul_lazy(
-            &self.value,
-            &other.value,
+            &a.value,
+            &b.value,
             &to_limbs(&self.constants.modulus, self.value.len()),
             &to_limbs(&self.constants.n_prime, self.value.len())
         );
@@ -169,7 +162,48 @@ impl MontgomeryForm {
         Self {
             value: result,
             constants: self.constants.clone(),
-            extra_precision: self.extra_precision + other.extra_precision + 1
+            extra_precision: a.extra_precision + b.extra_precision + 1
+        }
+    }
+
+    /// Computes `self * b + c` with a single final reduction
+    ///
+    /// The lazy product is below `2N`, so adding a canonical `c` stays below `3N`
+    /// and at most two subtractions bring the sum back into `[0, N)`.
+    pub fn mul_add(&self, b: &Self, c: &Self) -> Self {
+        assert_eq!(self.constants.modulus, b.constants.modulus);
+        assert_eq!(self.constants.modulus, c.constants.modulus);
+        
+        let num_limbs = self.value.len();
+        let modulus_limbs = to_limbs(&self.constants.modulus, num_limbs);
+        let canonical = |x: &Self| {
+            let mut value = x.value.clone();
+            if x.extra_precision > 0 {
+                while !ct_lt(&value, &modulus_limbs) {
+                    sub_limbs(&mut value, &modulus_limbs);
+                }
+            }
+            value
+        };
+        
+        let mut result = mont_mul_lazy(
+            &canonical(self),
+            &canonical(b),
+            &modulus_limbs,
+            &to_limbs(&self.constants.n_prime, num_limbs)
+        );
+        
+        // A borrow out of the top limb cancels a carry from the addition
+        let mut carry = add_limbs(&mut result, &canonical(c));
+        while carry || !ct_lt(&result, &modulus_limbs) {
+            let borrow = sub_limbs(&mut result, &modulus_limbs);
+            carry &= !borrow;
+        }
+        
+        Self {
+            value: result,
+            constants: self.constants.clone(),
+            extra_precision: 0
         }
     }
 
@@ -179,50 +213,45 @@ impl MontgomeryForm {
         #[cfg(all(feature = "simd", target_arch = "x86_64"))]
         if self.value.len() == 8 && simd_avx512::has_avx512f() {
             unsafe {
+                // The vector kernels correct by at most one modulus, so they need
+                // operands below N rather than lazily accumulated multiples of it
                 let result = simd_avx512::field_add_avx512(
-                    &self.value,
-                    &other.value,
+                    &self.reduced().value,
+                    &other.reduced().value,
                     &to_limbs(&self.constants.modulus, self.value.len())
                 );
                 
-                // Track extra precision
+                // The kernels resolve carries across limbs, so the result is canonical
                 return Self {
                     value: result,
                     constants: self.constants.clone(),
-                    extra_precision: self.extra_precision.max(other.extra_precision)
+                    extra_precision: 0
                 };
             }
         }
         
-        // Perform addition with lazy reduction
-        let mut result = self.value.clone();
+        // Lazy operands may hold several multiples of N, so canonicalise them first;
+        // otherwise one conditional subtraction could leave the sum above N
+        let mut result = self.reduced().value;
+        let other = other.reduced();
         let modulus_limbs = to_limbs(&self.constants.modulus, self.value.len());
         
         let mut carry = 0u64;
-        for i in 0..result.len() {
-            let sum = (result[i] as u128) + (other.value[i] as u128) + (carry as u128);
-            result[i] = sum as u64;
+        for (r, &o) in result.iter_mut().zip(other.value.iter()) {
+            let sum = (*r as u128) + (o as u128) + (carry as u128);
+            *r = sum as u64;
             carry = (sum >> 64) as u64;
         }
         
-        // Only reduce if value exceeds modulus by a threshold
-        let needs_reduction = carry > 0 || !ct_lt(&result, &modulus_limbs);
-        if needs_reduction {
-            let mut borrow = 0i64;
-            for i in 0..result.len() {
-                let diff = (result[i] as i128) - (modulus_limbs[i] as i128) - (borrow as i128);
-                result[i] = diff as u64;
-                borrow = if diff < 0 { 1 } else { 0 };
-            }
+        // A single subtraction suffices since both operands are below the modulus
+        if carry > 0 || !ct_lt(&result, &modulus_limbs) {
+            sub_limbs(&mut result, &modulus_limbs);
         }
         
-        // Track extra precision - addition shouldn't increase precision much
-        let new_precision = self.extra_precision.max(other.extra_precision);
-        
         Self {
             value: result,
             constants: self.constants.clone(),
-            extra_precision: new_precision
+            extra_precision: 0
         }
     }
     
@@ -233,307 +262,234 @@ impl MontgomeryForm {
         #[cfg(all(feature = "simd", target_arch = "x86_64"))]
         if self.value.len() == 8 && simd_avx512::has_avx512f() {
             unsafe {
+                // The vector kernels correct by at most one modulus, so they need
+                // operands below N rather than lazily accumulated multiples of it
                 let result = simd_avx512::field_sub_avx512(
-                    &self.value,
-                    &other.value,
+                    &self.reduced().value,
+                    &other.reduced().value,
                     &to_limbs(&self.constants.modulus, self.value.len())
                 );
                 
-                // Track extra precision
+                // The kernels resolve carries across limbs, so the result is canonical
                 return Self {
                     value: result,
                     constants: self.constants.clone(),
-                    extra_precision: self.extra_precision.max(other.extra_precision)
+                    extra_precision: 0
                 };
             }
         }
         
-        // Perform subtraction with lazy reduction
-        let mut result = self.value.clone();
+        // Perform subtraction on canonical operands
+        let mut result = self.reduced().value;
+        let other = other.reduced();
         let modulus_limbs = to_limbs(&self.constants.modulus, self.value.len());
         
-        // If self < other, add the modulus first
-        if ct_lt(&self.value, &other.value) {
-            let mut carry = 0u64;
-            for i in 0..result.len() {
-                let sum = (result[i] as u128) + (modulus_limbs[i] as u128) + (carry as u128);
-                result[i] = sum as u64;
-                carry = (sum >> 64) as u64;
-            }
-        }
-        
-        // Now perform the subtraction
-        let mut borrow = 0i64;
-        for i in 0..result.len() {
-            let diff = (result[i] as i128) - (other.value[i] as i128) - (borrow as i128);
-            result[i] = diff as u64;
-            borrow = if diff < 0 { 1 } else { 0 };
-        }
-        
-        // If we still have a borrow, add the modulus again
-        if borrow != 0 {
-            let mut carry = 0u64;
-            for i in 0..result.len() {
-                let sum = (result[i] as u128) + (modulus_limbs[i] as u128) + (carry as u128);
-                result[i] = sum as u64;
-                carry = (sum >> 64) as u64;
-            }
+        // If we borrowed out of the top limb, wrap around by adding the modulus
+        if sub_limbs(&mut result, &other.value) {
+            add_limbs(&mut result, &modulus_limbs);
         }
         
         Self {
             value: result,
             constants: self.constants.clone(),
-            extra_precision: self.extra_precision.max(other.extra_precision)
+            extra_precision: 0
         }
     }
     
-    /// Squares the value with lazy reduction (optimized version of mul)
-    pub fn square(&mut self) -> Self {
-        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
-        if self.value.len() == 8 && simd_avx512::has_avx512f() {
-            // For squaring, we can use the same mul routine
-            return self.mul(self);
-        }
-        
-        // Specialized squaring algorithm with lazy reduction
-        let modulus_limbs = to_limbs(&self.constants.modulus, self.value.len());
-        let n_prime_limbs = to_limbs(&self.constants.n_prime, self.value.len());
+    /// Multiplies by a small integer without converting it to Montgomery form
+    ///
+    /// `mont(a) * k = mont(a * k)`, so a single limb-by-word multiply followed by
+    /// one reduction of the widened product is enough.
+    pub fn mul_u64(&self, k: u64) -> Self {
+        let a = self.reduced();
         
-        // Compute efficient squaring with fewer multiplications
-        let mut t = vec![0u64; self.value.len() * 2];
-        
-        // Diagonal terms
-        for i in 0..self.value.len() {
-            let sq = (self.value[i] as u128) * (self.value[i] as u128);
-            t[i * 2] += sq as u64;
-            t[i * 2 + 1] += (sq >> 64) as u64;
-        }
-        
-        // Off-diagonal terms (multiplied by 2)
-        for i in 0..self.value.len() {
-            for j in i+1..self.value.len() {
-                let product = (self.value[i] as u128) * (self.value[j] as u128);
-                let lo = (product as u64).wrapping_mul(2);
-                let hi = ((product >> 64) as u64).wrapping_mul(2) 
-                       + (if lo < (product as u64) { 1 } else { 0 });
-                
-                // Add to result with carrying
-                let mut pos = i + j;
-                let mut carry = 0u64;
-                
-                let sum = (t[pos] as u128) + (lo as u128) + (carry as u128);
-                t[pos] = sum as u64;
-                carry = (sum >> 64) as u64;
-                
-                pos += 1;
-                let sum = (t[pos] as u128) + (hi as u128) + (carry as u128);
-                t[pos] = sum as u64;
-                carry = (sum >> 64) as u64;
-                
-                // Propagate the carry
-                pos += 1;
-                while carry > 0 && pos < t.len() {
-                    let sum = (t[pos] as u128) + (carry as u128);
-                    t[pos] = sum as u64;
-                    carry = (sum >> 64) as u64;
-                    pos += 1;
-                }
-            }
+        let mut product = Vec::with_capacity(a.value.len() + 1);
+        let mut carry = 0u64;
+        for &limb in a.value.iter() {
+            let wide = (limb as u128) * (k as u128) + (carry as u128);
+            product.push(wide as u64);
+            carry = (wide >> 64) as u64;
         }
+        product.push(carry);
         
-        // Now perform Montgomery reduction (can be lazy)
-        let result = mont_reduce(&t, &modulus_limbs, &n_prime_limbs);
+        let reduced = BigUint::from_bytes_le(&to_bytes(&product)) % &self.constants.modulus;
         
         Self {
-            value: result,
+            value: to_limbs(&reduced, a.value.len()),
+            extra_precision: 0,
             constants: self.constants.clone(),
-            extra_precision: self.extra_precision * 2 + 1
-        }
-    }
-}
-
-/// Montgomery multiplication with optimized implementation
-pub fn mont_mul(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
-    // Compute t = a * b
-    let mut t = vec![0u64; a.len() * 2];
-    for i in 0..a.len() {
-        let mut carry = 0u64;
-        for j in 0..b.len() {
-            let product = (a[i] as u128) * (b[j] as u128) + (t[i + j] as u128) + (carry as u128);
-            t[i + j] = product as u64;
-            carry = (product >> 64) as u64;
         }
-        t[i + b.len()] = carry;
     }
     
-    // Compute m = (t mod R) * n' mod R
-    let mut m = vec![0u64; a.len()];
-    for i in 0..a.len() {
-        let mu = ((t[i] as u128) * (n_prime[0] as u128)) as u64;
-        m[i] = mu;
-    }
-    
-    // Compute t = (t + m*n) / R
-    let mut carry = 0u64;
-    for i in 0..a.len() {
-        let mut carry2 = 0u64;
-        for j in 0..n.len() {
-            let product = (m[i] as u128) * (n[j] as u128) + (t[i + j] as u128) + (carry2 as u128);
-            t[i + j] = product as u64;
-            carry2 = (product >> 64) as u64;
+    /// Negates the value in place, leaving it canonical
+    pub fn neg_assign(&mut self) {
+        self.reduce();
+        if self.value.iter().all(|&x| x == 0) {
+            return;
+        }
+        
+        // N - value, computed limb by limb without a temporary result
+        let modulus_limbs = to_limbs(&self.constants.modulus, self.value.len());
+        let mut borrow = 0u64;
+        for (v, &n) in self.value.iter_mut().zip(modulus_limbs.iter()) {
+            let (d1, b1) = n.overflowing_sub(*v);
+            let (d2, b2) = d1.overflowing_sub(borrow);
+            *v = d2;
+            borrow = (b1 | b2) as u64;
         }
-        let sum = (t[i + n.len()] as u128) + (carry2 as u128) + (carry as u128);
-        t[i + n.len()] = sum as u64;
-        carry = (sum >> 64) as u64;
     }
     
-    // Extract higher limbs as the result
-    let mut result = vec![0u64; a.len()];
-    for i in 0..a.len() {
-        result[i] = t[i + a.len()];
+    /// Squares the value with lazy reduction
+    pub fn square(&mut self) -> Self {
+        let base = self.reduced();
+        self.clone().mul(&base)
     }
+}
+
+/// Montgomery multiplication (CIOS) returning a fully reduced result in `[0, N)`
+pub fn mont_mul(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
+    let (mut result, overflow) = mont_mul_cios(a, b, n, n_prime);
     
     // Final reduction step
-    if !ct_lt(&result, n) {
-        let mut borrow = 0i64;
-        for i in 0..result.len() {
-            let diff = (result[i] as i128) - (n[i] as i128) - (borrow as i128);
-            result[i] = diff as u64;
-            borrow = if diff < 0 { 1 } else { 0 };
-        }
+    if overflow || !ct_lt(&result, n) {
+        sub_limbs(&mut result, n);
     }
     
     result
 }
 
+/// Montgomery multiplication whose running time does not depend on the operand values
+///
+/// Like `mont_mul`, but the final subtraction of N is always computed and the
+/// result is selected with a mask instead of a branch.
+pub fn mont_mul_ct(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
+    let (result, overflow) = mont_mul_cios(a, b, n, n_prime);
+    let mut reduced = result.clone();
+    let borrow = sub_limbs(&mut reduced, n);
+    
+    // Keep the subtraction if the value overflowed or did not borrow
+    let mask = ((overflow | !borrow) as u64).wrapping_neg();
+    result
+        .iter()
+        .zip(reduced.iter())
+        .map(|(&r, &d)| (d & mask) | (r & !mask))
+        .collect()
+}
+
 /// Montgomery multiplication with lazy reduction for improved performance
+///
+/// For operands below N the result lies in `[0, 2N)`; the final conditional
+/// subtraction is skipped unless the result overflowed the limb buffer.
 pub fn mont_mul_lazy(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
-    // Specialized version that skips the final reduction step
-    let mut t = vec![0u64; a.len() * 2];
-    
-    // Step 1: Compute t = a * b
-    for i in 0..a.len() {
+    let (mut result, overflow) = mont_mul_cios(a, b, n, n_prime);
+    if overflow {
+        sub_limbs(&mut result, n);
+    }
+    result
+}
+
+/// Coarsely integrated operand scanning: computes `a * b * R^-1` and reports
+/// whether the result spilled past the top limb
+fn mont_mul_cios(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> (Vec<u64>, bool) {
+    let s = n.len();
+    let mut t = vec![0u64; s + 2];
+    
+    for &b_i in b.iter().take(s) {
+        // t += a * b_i
         let mut carry = 0u64;
-        for j in 0..b.len() {
-            let product = (a[i] as u128) * (b[j] as u128) + (t[i + j] as u128) + (carry as u128);
-            t[i + j] = product as u64;
+        for j in 0..s {
+            let product = (a[j] as u128) * (b_i as u128) + (t[j] as u128) + (carry as u128);
+            t[j] = product as u64;
             carry = (product >> 64) as u64;
         }
-        t[i + b.len()] = carry;
-    }
-    
-    // Step 2: Compute m = (t mod R) * n' mod R
-    let mut m = vec![0u64; a.len()];
-    for i in 0..a.len() {
-        let mu = ((t[i] as u128) * (n_prime[0] as u128)) as u64;
-        m[i] = mu;
-    }
-    
-    // Step 3: Compute t = (t + m*n) / R
-    let mut carry = 0u64;
-    for i in 0..a.len() {
-        let mut carry2 = 0u64;
-        for j in 0..n.len() {
-            let product = (m[i] as u128) * (n[j] as u128) + (t[i + j] as u128) + (carry2 as u128);
-            t[i + j] = product as u64;
-            carry2 = (product >> 64) as u64;
+        let sum = (t[s] as u128) + (carry as u128);
+        t[s] = sum as u64;
+        t[s + 1] = (sum >> 64) as u64;
+        
+        // t = (t + m * n) / 2^64 where m makes the low limb vanish
+        let m = t[0].wrapping_mul(n_prime[0]);
+        let product = (m as u128) * (n[0] as u128) + (t[0] as u128);
+        let mut carry = (product >> 64) as u64;
+        for j in 1..s {
+            let product = (m as u128) * (n[j] as u128) + (t[j] as u128) + (carry as u128);
+            t[j - 1] = product as u64;
+            carry = (product >> 64) as u64;
         }
-        let sum = (t[i + n.len()] as u128) + (carry2 as u128) + (carry as u128);
-        t[i + n.len()] = sum as u64;
-        carry = (sum >> 64) as u64;
+        let sum = (t[s] as u128) + (carry as u128);
+        t[s - 1] = sum as u64;
+        t[s] = t[s + 1] + ((sum >> 64) as u64);
     }
     
-    // Extract higher limbs as the result
-    let mut result = vec![0u64; a.len()];
-    for i in 0..a.len() {
-        result[i] = t[i + a.len()];
-    }
-    
-    // Skip final reduction step for lazy reduction
-    result
+    let overflow = t[s] != 0;
+    t.truncate(s);
+    (t, overflow)
 }
 
 /// Montgomery reduction from double-precision to single-precision
 pub fn mont_reduce(t: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
     let num_limbs = n.len();
-    let mut result = vec![0u64; num_limbs];
-    
-    // Extract lower half of t
-    let mut t_lo = vec![0u64; num_limbs];
-    for i in 0..num_limbs {
-        t_lo[i] = t[i];
-    }
-    
-    // Compute m = t_lo * n' mod R
-    let mut m = vec![0u64; num_limbs];
-    for i in 0..num_limbs {
-        let mu = ((t_lo[i] as u128) * (n_prime[0] as u128)) as u64;
-        m[i] = mu;
-    }
+    let mut buf = vec![0u64; num_limbs * 2 + 1];
+    buf[..t.len().min(num_limbs * 2)].copy_from_slice(&t[..t.len().min(num_limbs * 2)]);
     
-    // Compute t = (t + m*n) / R
-    let mut carry = 0u64;
     for i in 0..num_limbs {
-        let mut carry2 = 0u64;
+        // Compute m = t_i * n' mod 2^64 and add m * n * 2^(64i)
+        let m = buf[i].wrapping_mul(n_prime[0]);
+        let mut carry = 0u64;
         for j in 0..num_limbs {
-            let idx = i + j;
-            if idx < t.len() {
-                let product = (m[i] as u128) * (n[j] as u128) + (t[idx] as u128) + (carry2 as u128);
-                t_lo[idx % num_limbs] = product as u64;
-                carry2 = (product >> 64) as u64;
-            }
+            let product = (m as u128) * (n[j] as u128) + (buf[i + j] as u128) + (carry as u128);
+            buf[i + j] = product as u64;
+            carry = (product >> 64) as u64;
+        }
+        
+        // Propagate the carry
+        let mut pos = i + num_limbs;
+        while carry > 0 && pos < buf.len() {
+            let sum = (buf[pos] as u128) + (carry as u128);
+            buf[pos] = sum as u64;
+            carry = (sum >> 64) as u64;
+            pos += 1;
         }
-        carry += carry2;
     }
     
-    // Extract result from higher half of t
-    for i in 0..num_limbs {
-        let idx = i + num_limbs;
-        result[i] = if idx < t.len() { t[idx] } else { 0 };
-    }
-    
-    // Add carry from the lower half computation
-    let mut c = 0u64;
-    for i in 0..num_limbs {
-        let sum = (result[i] as u128) + (c as u128);
-        result[i] = sum as u64;
-        c = (sum >> 64) as u64;
-    }
+    // Extract result from higher half
+    let mut result = buf[num_limbs..num_limbs * 2].to_vec();
     
     // Final reduction step
-    if !ct_lt(&result, n) {
-        let mut borrow = 0i64;
-        for i in 0..result.len() {
-            let diff = (result[i] as i128) - (n[i] as i128) - (borrow as i128);
-            result[i] = diff as u64;
-            borrow = if diff < 0 { 1 } else { 0 };
-        }
+    if buf[num_limbs * 2] != 0 || !ct_lt(&result, n) {
+        sub_limbs(&mut result, n);
     }
     
     result
 }
 
+/// Subtracts `b` from `a` in place, returning true if the subtraction borrowed
+fn sub_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut borrow = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (d1, b1) = x.overflowing_sub(y);
+        let (d2, b2) = d1.overflowing_sub(borrow as u64);
+        *x = d2;
+        borrow = b1 || b2;
+    }
+    borrow
+}
+
+/// Adds `b` to `a` in place, returning true if the addition carried
+fn add_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut carry = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (s1, c1) = x.overflowing_add(y);
+        let (s2, c2) = s1.overflowing_add(carry as u64);
+        *x = s2;
+        carry = c1 || c2;
+    }
+    carry
+}
+
 /// Converts a BigUint to a fixed-size array of limbs
 pub fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
-    let bytes = value.to_bytes_le();
-    let mut limbs = vec![0u64; num_limbs];
-    
-    for i in 0..num_limbs {
-        let start = i * 8;
-        if start >= bytes.len() {
-            break;
-        }
-        
-        let end = std::cmp::min(start + 8, bytes.len());
-        let mut limb = 0u64;
-        
-        for j in start..end {
-            limb |= (bytes[j] as u64) << (8 * (j - start));
-        }
-        
-        limbs[i] = limb;
-    }
-    
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(num_limbs, 0);
     limbs
 }
 
@@ -568,47 +524,24 @@ pub fn ct_lt(a: &[u64], b: &[u64]) -> bool {
 
 /// Calculates n' such that n * n' ≡ -1 (mod 2^64)
 fn calculate_n_prime(n: &BigUint, word_size: u32) -> BigUint {
-    let r = BigUint::from(1u64) << word_size;
-    let one = BigUint::from(1u64);
+    assert_eq!(word_size, 64, "Only 64-bit words are supported");
+    let n0 = n.to_u64_digits().first().copied().unwrap_or(0);
+    assert_eq!(n0 & 1, 1, "Modulus is not invertible modulo 2^64");
     
-    // n' = -n^(-1) mod 2^64
-    // First, calculate n mod 2^64
-    let n_mod_r = n % &r;
-    
-    // Extended Euclidean algorithm to find inverse of n modulo 2^64
-    let mut t = BigUint::from(0u64);
-    let mut new_t = BigUint::from(1u64);
-    let mut r_val = r.clone();
-    let mut new_r = n_mod_r.clone();
-    
-    while !new_r.is_zero() {
-        let quotient = &r_val / &new_r;
-        let temp_t = t.clone();
-        t = new_t.clone();
-        new_t = temp_t - &quotient * &new_t;
-        
-        let temp_r = r_val.clone();
-        r_val = new_r.clone();
-        new_r = temp_r - &quotient * &new_r;
-    }
-    
-    // If r_val > 1, n is not invertible modulo 2^64
-    assert_eq!(r_val, one, "Modulus is not invertible modulo 2^64");
-    
-    // Ensure t is positive
-    while t < BigUint::from(0u64) {
-        t = t + &r;
+    // Newton iteration doubles the number of correct low bits each step
+    let mut inv = 1u64;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(n0.wrapping_mul(inv)));
     }
     
-    // Compute n' = -t mod 2^64
-    (&r - &t) % &r
+    // n' = -n^(-1) mod 2^64
+    BigUint::from(inv.wrapping_neg())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use num_bigint::BigUint;
-    use std::str::FromStr;
     
     #[test]
     fn test_basic_conversion() {
@@ -621,7 +554,7 @@ mod tests {
         let mont_form = MontgomeryForm::new(to_limbs(&value, 4), constants.clone());
         
         // Convert back to regular form
-        let one_limbs = vec![1u64; 4];
+        let one_limbs = vec![1u64, 0, 0, 0];
         let n_limbs = to_limbs(&modulus, 4);
         let n_prime_limbs = to_limbs(&constants.n_prime, 4);
         
@@ -650,7 +583,7 @@ mod tests {
         let result = a_mont.add(&b_mont);
         
         // Convert back to regular form
-        let one_limbs = vec![1u64; 4];
+        let one_limbs = vec![1u64, 0, 0, 0];
         let n_limbs = to_limbs(&modulus, 4);
         let n_prime_limbs = to_limbs(&constants.n_prime, 4);
         
@@ -679,7 +612,7 @@ mod tests {
         let result = a_mont.sub(&b_mont);
         
         // Convert back to regular form
-        let one_limbs = vec![1u64; 4];
+        let one_limbs = vec![1u64, 0, 0, 0];
         let n_limbs = to_limbs(&modulus, 4);
         let n_prime_limbs = to_limbs(&constants.n_prime, 4);
         
@@ -726,7 +659,7 @@ mod tests {
         let result = a_mont.mul(&b_mont);
         
         // Convert back to regular form
-        let one_limbs = vec![1u64; 4];
+        let one_limbs = vec![1u64, 0, 0, 0];
         let n_limbs = to_limbs(&modulus, 4);
         let n_prime_limbs = to_limbs(&constants.n_prime, 4);
         
@@ -736,4 +669,82 @@ mod tests {
         // Verify result
         assert_eq!(result_val, expected);
     }
+    
+    #[test]
+    fn test_mont_mul_ct_matches_mont_mul() {
+        let modulus = BigUint::parse_bytes(
+            b"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            16,
+        )
+        .unwrap();
+        let constants = MontgomeryConstants::new(&modulus, 64);
+        let n = to_limbs(&modulus, 4);
+        let n_prime = to_limbs(&constants.n_prime, 4);
+        let r_squared = to_limbs(&constants.r_squared, 4);
+        
+        for value in [BigUint::from(0u32), BigUint::from(1u32), &modulus >> 1, &modulus - 1u32] {
+            let limbs = to_limbs(&value, 4);
+            assert_eq!(mont_mul_ct(&limbs, &r_squared, &n, &n_prime), mont_mul(&limbs, &r_squared, &n, &n_prime));
+            assert_eq!(mont_mul_ct(&limbs, &limbs, &n, &n_prime), mont_mul(&limbs, &limbs, &n, &n_prime));
+        }
+    }
+    
+    #[test]
+    fn test_long_multiply_chain_stays_bounded() {
+        // 2^256 - 189 fills all four limbs, so a growing lazy bound would overflow
+        let modulus = (BigUint::from(1u32) << 256usize) - 189u32;
+        let constants = MontgomeryConstants::new(&modulus, 64);
+        let n_limbs = to_limbs(&modulus, 4);
+        let n_prime_limbs = to_limbs(&constants.n_prime, 4);
+        let one_limbs = vec![1u64, 0, 0, 0];
+        
+        let base_val = &modulus - 2u32;
+        let base = MontgomeryForm::new(to_limbs(&base_val, 4), constants.clone());
+        
+        let mut acc = base.clone();
+        let mut expected = base_val.clone();
+        for i in 0..1000 {
+            acc = if i % 3 == 0 { acc.square() } else { acc.mul(&base) };
+            expected = if i % 3 == 0 { &expected * &expected } else { expected * &base_val } % &modulus;
+            assert!(acc.extra_precision <= 1);
+        }
+        
+        acc.reduce();
+        let result_limbs = mont_mul(&acc.value, &one_limbs, &n_limbs, &n_prime_limbs);
+        assert_eq!(BigUint::from_bytes_le(&to_bytes(&result_limbs)), expected);
+    }
+    
+    #[test]
+    fn test_add_sub_with_lazy_near_max_operands() {
+        // BN254 base field leaves two spare bits in the top limb
+        let modulus = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088696311157297823662689037894645226208583",
+            10
+        ).unwrap();
+        let constants = MontgomeryConstants::new(&modulus, 64);
+        let lazy = |value: &BigUint| MontgomeryForm {
+            value: to_limbs(value, 4),
+            extra_precision: 2,
+            constants: constants.clone(),
+        };
+        let canonical = |form: &MontgomeryForm| {
+            let value = BigUint::from_bytes_le(&to_bytes(&form.value));
+            assert!(value < modulus);
+            value
+        };
+        
+        // 4N - 1 and 3N + (N - 3) are both congruent to values just below N
+        let mut a = lazy(&(&modulus * 4u32 - 1u32));
+        let b = lazy(&(&modulus * 4u32 - 3u32));
+        
+        let sum = a.add(&b);
+        assert_eq!(canonical(&sum), (&modulus * 2u32 - 4u32) % &modulus);
+        
+        let difference = a.sub(&b);
+        assert_eq!(canonical(&difference), BigUint::from(2u32));
+        
+        let mut b = b;
+        let difference = b.sub(&a);
+        assert_eq!(canonical(&difference), &modulus - 2u32);
+    }
 } 
\ No newline at end of file
@@ -1,108 +1,228 @@
-#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+//! AVX-512 kernels for 512-bit field elements stored as 8 little-endian 64-bit limbs
+//!
+//! The 8 lanes of a `__m512i` hold the limbs of a single element, so the lanes are
+//! not independent: carries and borrows are resolved across lanes with the mask
+//! registers. Callers check `has_avx512f()` at runtime before using these.
+
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(target_arch = "x86_64")]
+use num_bigint::BigUint;
+#[cfg(target_arch = "x86_64")]
+use super::montgomery::{mont_mul_lazy, to_limbs};
+
+/// Number of 64-bit limbs processed by each kernel
+pub const LIMBS: usize = 8;
 
-/// Checks if AVX-512F is available
+/// Checks if AVX-512F is available on the running CPU
 #[inline]
 pub fn has_avx512f() -> bool {
     #[cfg(target_arch = "x86_64")]
-    unsafe {
-        std::is_x86_feature_detected!("avx512f")
+    {
+        is_x86_feature_detected!("avx512f")
     }
     #[cfg(not(target_arch = "x86_64"))]
-    false
+    {
+        false
+    }
 }
 
-/// Performs field multiplication using AVX-512
-#[cfg(target_feature = "avx512f")]
+/// Resolves a carry (or borrow) chain across the 8 limb lanes
+///
+/// `generate` marks lanes that produce a carry on their own and `propagate` marks
+/// lanes that pass an incoming carry on (all ones for addition, zero for
+/// subtraction); the two are disjoint. Returns the lanes that receive a carry and
+/// whether one leaves the top lane.
+#[inline]
+fn resolve_carries(generate: u8, propagate: u8) -> (u8, bool) {
+    let chain = ((generate as u16) << 1) + propagate as u16;
+    (((chain ^ propagate as u16) & 0xff) as u8, chain & 0x100 != 0)
+}
+
+/// Computes `a + b` over 8 limbs, returning the sum and the carry out of the top limb
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512f")]
-pub unsafe fn field_mul_avx512(a: &[u64], b: &[u64], modulus: &[u64]) -> Vec<u64> {
-    assert!(a.len() == 8 && b.len() == 8 && modulus.len() == 8);
-    
-    let mut result = vec![0u64; 8];
-    
-    // Load values into AVX-512 registers
-    let a_vec = _mm512_loadu_epi64(a.as_ptr() as *const i64);
-    let b_vec = _mm512_loadu_epi64(b.as_ptr() as *const i64);
-    let m_vec = _mm512_loadu_epi64(modulus.as_ptr() as *const i64);
-    
-    // Multiply using AVX-512
-    let mut t = _mm512_mul_epu32(a_vec, b_vec);
-    
-    // Reduce modulo m
-    let mask = _mm512_cmpgt_epu64_mask(t, m_vec);
-    if mask != 0 {
-        t = _mm512_sub_epi64(t, m_vec);
+unsafe fn add_wide(a: __m512i, b: __m512i) -> (__m512i, bool) {
+    let sum = _mm512_add_epi64(a, b);
+    let generate = _mm512_cmplt_epu64_mask(sum, a);
+    let propagate = _mm512_cmpeq_epi64_mask(sum, _mm512_set1_epi64(-1));
+    let (carry_in, carry_out) = resolve_carries(generate, propagate);
+    (_mm512_mask_add_epi64(sum, carry_in, sum, _mm512_set1_epi64(1)), carry_out)
+}
+
+/// Computes `a - b` over 8 limbs, returning the difference and the borrow out of the top limb
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn sub_wide(a: __m512i, b: __m512i) -> (__m512i, bool) {
+    let diff = _mm512_sub_epi64(a, b);
+    let generate = _mm512_cmplt_epu64_mask(a, b);
+    let propagate = _mm512_cmpeq_epi64_mask(a, b);
+    let (borrow_in, borrow_out) = resolve_carries(generate, propagate);
+    (_mm512_mask_sub_epi64(diff, borrow_in, diff, _mm512_set1_epi64(1)), borrow_out)
+}
+
+/// Subtracts `m` once if `value` (plus an overflowed top bit `carry`) is at least `m`
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn reduce_once(value: __m512i, carry: bool, m: __m512i) -> __m512i {
+    let (diff, borrow) = sub_wide(value, m);
+    if carry || !borrow {
+        diff
+    } else {
+        value
     }
-    
-    // Store result
-    _mm512_storeu_epi64(result.as_mut_ptr() as *mut i64, t);
-    
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn load(limbs: &[u64]) -> __m512i {
+    _mm512_loadu_epi64(limbs.as_ptr() as *const i64)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn store(value: __m512i) -> Vec<u64> {
+    let mut result = vec![0u64; LIMBS];
+    _mm512_storeu_epi64(result.as_mut_ptr() as *mut i64, value);
     result
 }
 
-/// Performs field addition using AVX-512
-#[cfg(target_feature = "avx512f")]
+/// Computes `a + b mod modulus` for 512-bit operands below `modulus`
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F; check `has_avx512f()` first.
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512f")]
 pub unsafe fn field_add_avx512(a: &[u64], b: &[u64], modulus: &[u64]) -> Vec<u64> {
-    assert!(a.len() == 8 && b.len() == 8 && modulus.len() == 8);
-    
-    let mut result = vec![0u64; 8];
-    
-    // Load values into AVX-512 registers
-    let a_vec = _mm512_loadu_epi64(a.as_ptr() as *const i64);
-    let b_vec = _mm512_loadu_epi64(b.as_ptr() as *const i64);
-    let m_vec = _mm512_loadu_epi64(modulus.as_ptr() as *const i64);
-    
-    // Add using AVX-512
-    let mut sum = _mm512_add_epi64(a_vec, b_vec);
-    
-    // Reduce modulo m
-    let mask = _mm512_cmpgt_epu64_mask(sum, m_vec);
-    if mask != 0 {
-        sum = _mm512_sub_epi64(sum, m_vec);
+    assert!(a.len() == LIMBS && b.len() == LIMBS && modulus.len() == LIMBS);
+
+    let m = load(modulus);
+    let (sum, carry) = add_wide(load(a), load(b));
+    store(reduce_once(sum, carry, m))
+}
+
+/// Computes `a - b mod modulus` for 512-bit operands below `modulus`
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F; check `has_avx512f()` first.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+pub unsafe fn field_sub_avx512(a: &[u64], b: &[u64], modulus: &[u64]) -> Vec<u64> {
+    assert!(a.len() == LIMBS && b.len() == LIMBS && modulus.len() == LIMBS);
+
+    let (diff, borrow) = sub_wide(load(a), load(b));
+    if borrow {
+        // The wrapped difference plus the modulus carries out exactly once
+        let (wrapped, _) = add_wide(diff, load(modulus));
+        return store(wrapped);
     }
-    
-    // Store result
-    _mm512_storeu_epi64(result.as_mut_ptr() as *mut i64, sum);
-    
-    result
+    store(diff)
 }
 
-/// Performs vectorized Montgomery multiplication using AVX-512
-#[cfg(target_feature = "avx512f")]
+/// Computes `a * b * R^-1 mod n` with `R = 2^512`, fully reduced
+///
+/// AVX-512F has no 64×64→128-bit lane multiply, so the limb products run through
+/// the scalar CIOS loop and only the final correction is vectorised.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F; check `has_avx512f()` first.
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx512f")]
 pub unsafe fn mont_mul_avx512(a: &[u64], b: &[u64], n: &[u64], n_prime: &[u64]) -> Vec<u64> {
-    assert!(a.len() == 8 && b.len() == 8 && n.len() == 8 && n_prime.len() == 8);
-    
-    let mut result = vec![0u64; 8];
-    
-    // Load values into AVX-512 registers
-    let a_vec = _mm512_loadu_epi64(a.as_ptr() as *const i64);
-    let b_vec = _mm512_loadu_epi64(b.as_ptr() as *const i64);
-    let n_vec = _mm512_loadu_epi64(n.as_ptr() as *const i64);
-    let np_vec = _mm512_loadu_epi64(n_prime.as_ptr() as *const i64);
-    
-    // Compute t = a * b
-    let t = _mm512_mul_epu32(a_vec, b_vec);
-    
-    // Compute m = t * n' mod R
-    let m = _mm512_mul_epu32(t, np_vec);
-    
-    // Compute t = (t + m * n) / R
-    let mn = _mm512_mul_epu32(m, n_vec);
-    let sum = _mm512_add_epi64(t, mn);
-    let r = _mm512_srli_epi64(sum, 32);
-    
-    // Final reduction
-    let mask = _mm512_cmpgt_epu64_mask(r, n_vec);
-    let final_result = if mask != 0 {
-        _mm512_sub_epi64(r, n_vec)
-    } else {
-        r
-    };
-    
-    // Store result
-    _mm512_storeu_epi64(result.as_mut_ptr() as *mut i64, final_result);
-    
-    result
-} 
\ No newline at end of file
+    assert!(a.len() == LIMBS && b.len() == LIMBS && n.len() == LIMBS && n_prime.len() == LIMBS);
+
+    // For operands below n the lazy product lies in [0, 2n), so one subtraction suffices
+    let product = mont_mul_lazy(a, b, n, n_prime);
+    store(reduce_once(load(&product), false, load(n)))
+}
+
+/// Computes `a * b mod modulus` for 512-bit operands below `modulus`
+///
+/// Multiplies in Montgomery form and then by `R² mod modulus` to cancel the `R^-1`.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F; check `has_avx512f()` first.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+pub unsafe fn field_mul_avx512(a: &[u64], b: &[u64], modulus: &[u64]) -> Vec<u64> {
+    assert!(a.len() == LIMBS && b.len() == LIMBS && modulus.len() == LIMBS);
+    assert_eq!(modulus[0] & 1, 1, "Modulus must be odd");
+
+    // -modulus^-1 mod 2^64 by Newton iteration
+    let mut inv = 1u64;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+    }
+    let mut n_prime = vec![0u64; LIMBS];
+    n_prime[0] = inv.wrapping_neg();
+
+    let n = BigUint::from_slice(
+        &modulus.iter().flat_map(|&limb| [limb as u32, (limb >> 32) as u32]).collect::<Vec<u32>>(),
+    );
+    let r_squared = to_limbs(&((BigUint::from(1u32) << (2 * 64 * LIMBS)) % n), LIMBS);
+
+    let reduced = mont_mul_avx512(a, b, modulus, &n_prime);
+    mont_mul_avx512(&reduced, &r_squared, modulus, &n_prime)
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+    use crate::arithmetic::montgomery::{mont_mul, MontgomeryConstants};
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    /// A 512-bit odd modulus with a full top limb, so carries out of lane 7 occur
+    fn test_modulus() -> BigUint {
+        (BigUint::from(1u32) << 512) - BigUint::from(569u32)
+    }
+
+    fn to_big(limbs: &[u64]) -> BigUint {
+        BigUint::from_slice(&limbs.iter().flat_map(|&l| [l as u32, (l >> 32) as u32]).collect::<Vec<u32>>())
+    }
+
+    #[test]
+    fn test_carry_resolution() {
+        // Lane 0 generates and lanes 1..=3 are all ones, so the carry runs to lane 4
+        assert_eq!(resolve_carries(0b0000_0001, 0b0000_1110), (0b0001_1110, false));
+        assert_eq!(resolve_carries(0b1000_0000, 0), (0, true));
+        assert_eq!(resolve_carries(0b0000_0001, 0b1111_1110), (0b1111_1110, true));
+    }
+
+    #[test]
+    fn test_kernels_match_scalar_path() {
+        if !has_avx512f() {
+            return;
+        }
+
+        let modulus = test_modulus();
+        let n = to_limbs(&modulus, LIMBS);
+        let n_prime = to_limbs(&MontgomeryConstants::new(&modulus, 64).n_prime, LIMBS);
+        let mut rng = rand::thread_rng();
+
+        // Random values plus the edge cases 0, 1 and modulus - 1
+        let mut values: Vec<BigUint> = (0..20)
+            .map(|_| {
+                let bytes: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+                BigUint::from_bytes_le(&bytes) % &modulus
+            })
+            .collect();
+        values.extend([BigUint::from(0u32), BigUint::from(1u32), &modulus - 1u32]);
+
+        for x in &values {
+            for y in &values {
+                let (a, b) = (to_limbs(x, LIMBS), to_limbs(y, LIMBS));
+                unsafe {
+                    assert_eq!(to_big(&field_add_avx512(&a, &b, &n)), (x + y) % &modulus);
+                    assert_eq!(to_big(&field_sub_avx512(&a, &b, &n)), (x + &modulus - y) % &modulus);
+                    assert_eq!(to_big(&field_mul_avx512(&a, &b, &n)), (x * y) % &modulus);
+                    assert_eq!(mont_mul_avx512(&a, &b, &n, &n_prime), mont_mul(&a, &b, &n, &n_prime));
+                }
+            }
+        }
+    }
+}
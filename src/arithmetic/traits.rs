@@ -1,4 +1,5 @@
 use std::ops::{Add, Sub, Mul, Div, Neg};
+use num_bigint::BigUint;
 use num_traits::{Zero, One};
 
 /// Trait for field elements with basic arithmetic operations
@@ -15,8 +16,20 @@ pub trait Field:
     + Zero
     + One
 {
-    /// Returns the characteristic of the field
-    fn characteristic() -> Vec<u64>;
+    /// Returns the characteristic of this element's field as little-endian 64-bit limbs
+    fn characteristic(&self) -> Vec<u64>;
+
+    /// Returns the characteristic of this element's field as a `BigUint`
+    ///
+    /// Generic code such as root-of-unity searches uses this instead of the raw limbs.
+    fn modulus_biguint(&self) -> BigUint {
+        BigUint::from_slice(
+            &self.characteristic()
+                .iter()
+                .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+                .collect::<Vec<u32>>(),
+        )
+    }
 
     /// Returns the multiplicative inverse of this element
     fn inverse(&self) -> Option<Self>;
@@ -28,16 +41,56 @@ pub trait Field:
 
     /// Raises this element to a power
     fn pow(&self, exp: u64) -> Self;
+
+    /// Computes `self * b + c`
+    ///
+    /// Fields with lazy reduction override this to reduce only once.
+    fn mul_add(&self, b: &Self, c: &Self) -> Self {
+        self.clone() * b.clone() + c.clone()
+    }
+
+    /// Applies the Frobenius endomorphism `x -> x^(p^power)`
+    ///
+    /// This is the identity on prime fields; extension fields override it.
+    fn frobenius(&self, _power: usize) -> Self {
+        self.clone()
+    }
+
+    /// Returns the additive identity of this element's field
+    ///
+    /// `Zero::zero()` has no element to take the field from; fields whose modulus
+    /// is chosen at runtime override this so generic code stays in the right field.
+    fn zero_like(&self) -> Self {
+        Self::zero()
+    }
+
+    /// Returns the multiplicative identity of this element's field
+    fn one_like(&self) -> Self {
+        Self::one()
+    }
+
+    /// Multiplies this element by a small integer using double-and-add
+    fn mul_u64(&self, k: u64) -> Self {
+        let mut result = self.zero_like();
+        for i in (0..64 - k.leading_zeros()).rev() {
+            result = result.clone() + result;
+            if (k >> i) & 1 == 1 {
+                result = result + self.clone();
+            }
+        }
+        result
+    }
 }
 
 /// Trait for prime fields with modular arithmetic
 pub trait PrimeField: Field {
-    /// The modulus of the field
-    fn modulus() -> Vec<u64>;
+    /// The modulus of this element's field as little-endian 64-bit limbs
+    fn modulus(&self) -> Vec<u64>;
     
     /// Returns the field element in Montgomery form
     fn to_montgomery(&self) -> Self;
     
     /// Returns the field element from Montgomery form
+    #[allow(clippy::wrong_self_convention)]
     fn from_montgomery(&self) -> Self;
 } 
\ No newline at end of file
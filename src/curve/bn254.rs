@@ -1,12 +1,69 @@
 use crate::arithmetic::field::Fp;
-use num_bigint::BigUint;
-use std::ops::{Add, Mul, Neg};
+use num_bigint::{BigInt, BigUint, Sign};
+use std::ops::{Add, Mul, Neg, Sub};
 use std::str::FromStr;
 use crate::arithmetic::traits::Field;
+use crate::curve::glv;
+use crate::curve::scalar::Scalar;
+use lazy_static::lazy_static;
 use num_traits::Zero;
+use std::fmt;
+use thiserror::Error;
+
+/// Current version of the versioned point encoding
+pub const POINT_ENCODING_VERSION: u8 = 1;
+
+/// Flags byte of the versioned point encoding
+pub const POINT_FLAG_UNCOMPRESSED: u8 = 0x00;
+pub const POINT_FLAG_COMPRESSED: u8 = 0x01;
+pub const POINT_FLAG_INFINITY: u8 = 0x02;
+
+/// Cofactor of G2 in the twist, `2p - r`
+const G2_COFACTOR: &str =
+    "21888242871839275222246405745257275088844257914179612981679871602714643921549";
+
+/// The BN parameter `u`
+const BN_PARAMETER_U: u64 = 4965661367192848881;
+
+/// `6u²` for the BN parameter `u = 4965661367192848881`, the eigenvalue of ψ on G2
+const PSI_EIGENVALUE: &str = "147946756881789318990833708069417712966";
+
+/// Flag bits stored in the top of the first byte of a compressed encoding
+const COMPRESSED_FLAG_INFINITY: u8 = 0x80;
+const COMPRESSED_FLAG_Y_LARGEST: u8 = 0x40;
+const COMPRESSED_FLAG_MASK: u8 = COMPRESSED_FLAG_INFINITY | COMPRESSED_FLAG_Y_LARGEST;
+
+/// Errors returned when decoding curve points
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PointError {
+    /// The encoding was produced by an unknown format version
+    #[error("unknown point encoding version {0}")]
+    UnknownVersion(u8),
+    /// The flags byte is not a known encoding
+    #[error("unknown point encoding flags {0:#04x}")]
+    InvalidFlags(u8),
+    /// The encoding kind is known but not supported by this decoder
+    #[error("unsupported point encoding flags {0:#04x}")]
+    UnsupportedEncoding(u8),
+    /// The input has the wrong size
+    #[error("expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+    /// A coordinate is not below the field modulus
+    #[error("coordinate is not below the field modulus")]
+    NonCanonicalCoordinate,
+    /// The decoded point does not satisfy the curve equation
+    #[error("point is not on the curve")]
+    NotOnCurve,
+    /// The decoded point is on the curve but outside the prime-order subgroup
+    #[error("point is not in the prime-order subgroup")]
+    NotInSubgroup,
+    /// A point in a batch failed to decode
+    #[error("point {index} is invalid: {source}")]
+    InvalidBatchPoint { index: usize, source: Box<PointError> },
+}
 
 /// BN254 elliptic curve implementation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BN254 {
     /// The prime field modulus
     pub modulus: BigUint,
@@ -33,6 +90,17 @@ pub struct Fp2 {
     pub c1: Fp,
 }
 
+/// Precomputed multiples of a G1 base for fixed-window scalar multiplication
+///
+/// Built by `G1Affine::precompute_window`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowTable {
+    /// Bits per window
+    width: usize,
+    /// `points[i] = i · P`
+    points: Vec<G1Affine>,
+}
+
 /// A point in G2 represented in affine coordinates
 #[derive(Debug, Clone, PartialEq)]
 pub struct G2Affine {
@@ -41,9 +109,55 @@ pub struct G2Affine {
     pub infinity: bool,
 }
 
+/// A point in G1 represented in Jacobian coordinates `(X, Y, Z)` with `x = X/Z²`, `y = Y/Z³`
+///
+/// The identity is any point with `Z = 0`.
+#[derive(Debug, Clone)]
+pub struct G1Projective {
+    pub x: Fp,
+    pub y: Fp,
+    pub z: Fp,
+}
+
+/// A point in G2 represented in Jacobian coordinates over Fp2
+#[derive(Debug, Clone)]
+pub struct G2Projective {
+    pub x: Fp2,
+    pub y: Fp2,
+    pub z: Fp2,
+}
+
+lazy_static! {
+    static ref BN254_INSTANCE: BN254 = BN254::build();
+    static ref PSI_SCALAR: BigUint = BigUint::from_str(PSI_EIGENVALUE).unwrap();
+
+    /// Twist Frobenius coefficients `ξ^((p - 1)/3)`, `ξ^((p - 1)/2)` and `ξ^((p² - 1)/3)`
+    pub(crate) static ref TWIST_FROBENIUS: (Fp2, Fp2, Fp2) = {
+        let modulus = BN254::bn254().modulus.clone();
+        let xi = Fp2::one(&modulus).mul_by_nonresidue();
+        let x_coeff = xi.pow_biguint(&((&modulus - 1u32) / 3u32));
+        let y_coeff = xi.pow_biguint(&((&modulus - 1u32) / 2u32));
+        // ξ^((p² - 1)/3) = (ξ^((p - 1)/3))^(p + 1)
+        let x_coeff_sq = x_coeff.frobenius(1).mul(&x_coeff);
+        (x_coeff, y_coeff, x_coeff_sq)
+    };
+}
+
 impl BN254 {
     /// Creates a new BN254 curve instance
+    ///
+    /// Clones the cached instance from `bn254()` instead of re-parsing the modulus.
     pub fn new() -> Self {
+        Self::bn254().clone()
+    }
+    
+    /// Returns the shared curve instance, built on first use
+    #[allow(clippy::self_named_constructors)]
+    pub fn bn254() -> &'static BN254 {
+        &BN254_INSTANCE
+    }
+    
+    fn build() -> Self {
         // BN254 parameters
         let modulus = BigUint::from_str(
             "21888242871839275222246405745257275088696311157297823662689037894645226208583"
@@ -96,6 +210,21 @@ impl BN254 {
         }
     }
     
+    /// Returns the order `r` of G1 and G2 (the scalar field modulus)
+    pub fn group_order() -> BigUint {
+        Scalar::modulus()
+    }
+    
+    /// Returns the G1 cofactor, which is 1: every point on the curve is in G1
+    pub fn cofactor_g1() -> BigUint {
+        BigUint::from(1u32)
+    }
+    
+    /// Returns the G2 cofactor `h2 = 2p - r`, so the twist has `r · h2` points
+    pub fn cofactor_g2() -> BigUint {
+        BigUint::from_str(G2_COFACTOR).unwrap()
+    }
+    
     /// Checks if a point is on the curve
     pub fn is_on_curve(&self, point: &G1Affine) -> bool {
         if point.infinity {
@@ -111,6 +240,14 @@ impl BN254 {
         lhs == rhs
     }
     
+    /// Checks that a G2 point is on the twist and in the prime-order subgroup
+    ///
+    /// Uses `ψ(Q) = [6u²]Q`, which holds exactly on G2 for BN curves and needs a
+    /// 128-bit scalar multiplication instead of the 254-bit `[r]Q`.
+    pub fn is_in_subgroup_g2(&self, point: &G2Affine) -> bool {
+        self.is_on_curve_g2(point) && point.is_torsion_free()
+    }
+    
     /// Checks if a G2 point is on the curve
     pub fn is_on_curve_g2(&self, point: &G2Affine) -> bool {
         if point.infinity {
@@ -134,7 +271,7 @@ impl BN254 {
     fn twist_constant_b(&self) -> Fp2 {
         // For BN254, the twisted curve has b' = b / (u+9) = 3 / (u+9)
         // This is represented as an element in Fp2
-        // Simplified implementation - actual values would be computed from the curve parameters
+        // Precomputed value of `compute_twist_constant_b`, checked against it in the tests
         let b_c0 = Fp::new(
             BigUint::from_str("19485874751759354771024239261021720505790618469301721065564631296452457478373").unwrap(),
             self.modulus.clone()
@@ -146,6 +283,24 @@ impl BN254 {
         
         Fp2 { c0: b_c0, c1: b_c1 }
     }
+    
+    /// Computes the twisted curve constant b' = 3 / (9 + u) from the curve parameters
+    pub fn compute_twist_constant_b(&self) -> Fp2 {
+        let zero = Fp::new(BigUint::from(0u32), self.modulus.clone());
+        let three = Fp2::new(self.b.clone(), zero);
+        let xi = Fp2::new(
+            Fp::new(BigUint::from(9u32), self.modulus.clone()),
+            Fp::new(BigUint::from(1u32), self.modulus.clone()),
+        );
+        
+        three.mul(&xi.inverse().expect("9 + u is non-zero"))
+    }
+}
+
+impl Default for BN254 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl G1Affine {
@@ -159,6 +314,13 @@ impl G1Affine {
         }
     }
     
+    /// Converts projective points to affine with a single shared inversion
+    ///
+    /// Points with `Z = 0` become the affine identity. See `G1Projective::batch_to_affine`.
+    pub fn batch_normalize(projective: &[G1Projective]) -> Vec<G1Affine> {
+        G1Projective::batch_to_affine(projective)
+    }
+    
     /// Point doubling with lazy reduction
     pub fn double(&self) -> Self {
         if self.infinity {
@@ -195,6 +357,7 @@ impl G1Affine {
             y: y3,
             infinity: false,
         }
+        .debug_assert_on_curve()
     }
     
     /// Get the modulus of the field
@@ -202,38 +365,594 @@ impl G1Affine {
         self.x.modulus()
     }
     
+    /// Panics in debug builds if arithmetic produced a point off the BN254 curve
+    ///
+    /// Points over other moduli are not checked. Release builds skip the check entirely.
+    #[inline]
+    fn debug_assert_on_curve(self) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            let curve = BN254::bn254();
+            if self.modulus() == curve.modulus {
+                assert!(curve.is_on_curve(&self), "G1 arithmetic produced a point off the curve");
+            }
+        }
+        self
+    }
+    
     /// Windowed scalar multiplication using a window size of 4 bits
     pub fn windowed_mul(&self, scalar: &BigUint) -> Self {
+        self.precompute_window(4).mul(scalar)
+    }
+
+    /// Precomputes the multiples `0·P ..= (2^width - 1)·P` for windowed multiplication
+    ///
+    /// Building the table costs `2^width - 2` additions; reusing it lets many scalars
+    /// be multiplied against the same base without paying that again.
+    pub fn precompute_window(&self, width: usize) -> WindowTable {
+        assert!((1..=8).contains(&width), "Window size must be between 1 and 8 bits");
+        
+        let mut points = vec![Self::identity(&self.modulus()); 1 << width];
+        points[1] = self.clone();
+        for i in 2..(1 << width) {
+            points[i] = points[i - 1].clone() + self.clone();
+        }
+        
+        WindowTable { width, points }
+    }
+
+    /// Double-and-add over an explicit bit decomposition, most significant bit first
+    ///
+    /// Unlike `windowed_mul`, the caller controls the exact bits processed, so the
+    /// operation sequence can mirror an in-circuit decomposition (leading zeros and
+    /// non-canonical encodings included).
+    pub fn mul_bits(&self, bits: &[bool]) -> Self {
+        let base = G1Projective::from_affine(self);
+        let mut result = G1Projective::identity(&self.modulus());
+        for &bit in bits {
+            result = result.double();
+            if bit {
+                result = result + base.clone();
+            }
+        }
+        result.to_affine().debug_assert_on_curve()
+    }
+
+    /// Applies the GLV endomorphism `φ(x, y) = (βx, y)`, which equals `λ·P` on G1
+    pub fn endomorphism(&self) -> Self {
+        if self.infinity {
+            return self.clone();
+        }
+        Self {
+            x: self.x.clone() * Fp::new(glv::beta(), self.modulus()),
+            y: self.y.clone(),
+            infinity: false,
+        }
+    }
+
+    /// Scalar multiplication via the GLV decomposition `k = k1 + k2·λ`
+    ///
+    /// Both halves are about 128 bits, so a joint double-and-add over `k1·P` and
+    /// `k2·φ(P)` needs half the doublings of a full-width multiplication.
+    pub fn glv_mul(&self, scalar: &BigUint) -> Self {
+        let (k1, k2) = glv::decompose_scalar(scalar);
+        let p1 = if k1.sign() == Sign::Minus { -self } else { self.clone() };
+        let p2 = if k2.sign() == Sign::Minus { -self.endomorphism() } else { self.endomorphism() };
+        let (k1, k2) = (k1.magnitude(), k2.magnitude());
+        
+        let p1 = G1Projective::from_affine(&p1);
+        let p2 = G1Projective::from_affine(&p2);
+        let both = p1.clone() + p2.clone();
+        
+        let mut result = G1Projective::identity(&self.modulus());
+        for i in (0..k1.bits().max(k2.bits())).rev() {
+            result = result.double();
+            match (k1.bit(i), k2.bit(i)) {
+                (true, true) => result = result + both.clone(),
+                (true, false) => result = result + p1.clone(),
+                (false, true) => result = result + p2.clone(),
+                (false, false) => {}
+            }
+        }
+        result.to_affine().debug_assert_on_curve()
+    }
+
+    /// Scalar multiplication using the width-`width` non-adjacent form of `scalar`
+    ///
+    /// Only the odd multiples `P, 3P, ..., (2^(width-1) - 1)P` are precomputed;
+    /// negative digits reuse them with `y` negated. With nonzero digits at least
+    /// `width` positions apart, this needs far fewer additions than `windowed_mul`.
+    pub fn wnaf_mul(&self, scalar: &BigUint, width: usize) -> Self {
+        assert!((2..=8).contains(&width), "wNAF width must be between 2 and 8 bits");
+        
+        let terms = [(wnaf_digits(scalar, width), self.odd_multiples(width))];
+        wnaf_accumulate(&terms, &self.modulus()).to_affine().debug_assert_on_curve()
+    }
+
+    /// Multiplies by a scalar mod `r`, combining the GLV split with width-5 wNAF
+    ///
+    /// This is the fastest G1 scalar multiplication here and the one to reach for by
+    /// default. Each ~128-bit GLV half is recoded in wNAF, and the odd-multiple
+    /// table for `φ(P)` is the table for `P` with every `x` scaled by `β`.
+    pub fn mul_scalar(&self, s: &Scalar) -> Self {
+        const WIDTH: usize = 5;
+        
+        let (k1, k2) = glv::decompose_scalar(&s.to_biguint());
+        let table = self.odd_multiples(WIDTH);
+        let endo_table: Vec<Self> = table.iter().map(Self::endomorphism).collect();
+        
+        // Fold the sign of each half into its table
+        let signed = |table: Vec<Self>, k: &BigInt| match k.sign() {
+            Sign::Minus => table.iter().map(|p| -p).collect(),
+            _ => table,
+        };
+        let terms = [
+            (wnaf_digits(k1.magnitude(), WIDTH), signed(table, &k1)),
+            (wnaf_digits(k2.magnitude(), WIDTH), signed(endo_table, &k2)),
+        ];
+        wnaf_accumulate(&terms, &self.modulus()).to_affine().debug_assert_on_curve()
+    }
+
+    /// Returns the odd multiples `P, 3P, ..., (2^(width-1) - 1)P` used by wNAF
+    ///
+    /// The multiples are built in Jacobian coordinates and normalised together, so
+    /// the whole table costs one inversion.
+    fn odd_multiples(&self, width: usize) -> Vec<Self> {
+        let base = G1Projective::from_affine(self);
+        let double = base.double();
+        let mut odd_multiples = vec![base];
+        for i in 1..(1 << (width - 2)) {
+            odd_multiples.push(odd_multiples[i - 1].clone() + double.clone());
+        }
+        G1Projective::batch_to_affine(&odd_multiples)
+    }
+
+    /// Computes `sum(scalars[i] * bases[i])` with a single shared accumulator
+    ///
+    /// All terms are processed window by window from the most significant end,
+    /// so the doublings are paid once for the whole sum instead of once per term.
+    pub fn multi_mul(bases: &[Self], scalars: &[BigUint]) -> Self {
         const WINDOW_SIZE: usize = 4;
-        const WINDOW_MASK: u64 = (1 << WINDOW_SIZE) - 1;
+        assert_eq!(bases.len(), scalars.len(), "Bases and scalars must have the same length");
+
+        let modulus = match bases.first() {
+            Some(base) => base.modulus(),
+            None => return Self::identity(&BN254::new().modulus),
+        };
+
+        // Precompute 0..15 multiples of every base
+        let tables: Vec<Vec<Self>> = bases
+            .iter()
+            .map(|base| {
+                let mut table = vec![Self::identity(&modulus); 1 << WINDOW_SIZE];
+                table[1] = base.clone();
+                for i in 2..(1 << WINDOW_SIZE) {
+                    table[i] = table[i - 1].clone() + base.clone();
+                }
+                table
+            })
+            .collect();
+
+        let num_windows = scalars
+            .iter()
+            .map(|scalar| (scalar.bits() as usize).div_ceil(WINDOW_SIZE))
+            .max()
+            .unwrap_or(0);
+
+        let mut result = G1Projective::identity(&modulus);
+        for w in (0..num_windows).rev() {
+            for _ in 0..WINDOW_SIZE {
+                result = result.double();
+            }
+            for (table, scalar) in tables.iter().zip(scalars) {
+                let window = (scalar >> (w * WINDOW_SIZE))
+                    .to_u64_digits()
+                    .first()
+                    .copied()
+                    .unwrap_or(0) as usize
+                    & ((1 << WINDOW_SIZE) - 1);
+                if window != 0 {
+                    result = result.add_mixed(&table[window]);
+                }
+            }
+        }
+
+        result.to_affine()
+    }
+    
+    /// Multi-scalar multiplication, picking the algorithm from the number of terms
+    ///
+    /// Small inputs sum independent double-and-add products; larger ones use
+    /// Pippenger's bucket method with a window chosen by `msm_window_size`.
+    /// Empty input gives the BN254 identity and bases at infinity contribute nothing.
+    ///
+    /// Panics if `bases` and `scalars` differ in length.
+    pub fn msm(bases: &[Self], scalars: &[BigUint]) -> Self {
+        assert_eq!(bases.len(), scalars.len(), "Bases and scalars must have the same length");
+
+        let modulus = match bases.first() {
+            Some(base) => base.modulus(),
+            None => return Self::identity(&BN254::new().modulus),
+        };
+
+        let bases: Vec<G1Projective> = bases.iter().map(G1Projective::from_affine).collect();
+        msm_projective(&bases, scalars, G1Projective::identity(&modulus)).to_affine()
+    }
+}
+
+impl G1Affine {
+    /// Encodes the point as `version || flags || payload`
+    ///
+    /// The payload is the 64-byte big-endian `x || y` for affine points and empty
+    /// for the point at infinity. The version byte lets stored encodings be
+    /// migrated if the format ever changes.
+    pub fn to_bytes_versioned(&self) -> Vec<u8> {
+        if self.infinity {
+            return vec![POINT_ENCODING_VERSION, POINT_FLAG_INFINITY];
+        }
         
-        // Precompute points for each window
-        let mut window_points = vec![Self::identity(&self.modulus()); 1 << WINDOW_SIZE];
-        window_points[1] = self.clone();
-        for i in 2..(1 << WINDOW_SIZE) {
-            window_points[i] = window_points[i-1].clone() + self.clone();
+        let mut bytes = Vec::with_capacity(2 + 64);
+        bytes.push(POINT_ENCODING_VERSION);
+        bytes.push(POINT_FLAG_UNCOMPRESSED);
+        bytes.extend_from_slice(&coordinate_to_bytes(&self.x));
+        bytes.extend_from_slice(&coordinate_to_bytes(&self.y));
+        bytes
+    }
+    
+    /// Decodes a point produced by `to_bytes_versioned`, checking that it is on the curve
+    pub fn from_bytes_versioned(bytes: &[u8]) -> Result<Self, PointError> {
+        if bytes.len() < 2 {
+            return Err(PointError::InvalidLength { expected: 2, actual: bytes.len() });
+        }
+        if bytes[0] != POINT_ENCODING_VERSION {
+            return Err(PointError::UnknownVersion(bytes[0]));
         }
         
-        let mut result = Self::identity(&self.modulus());
-        let mut temp = self.clone();
+        let curve = BN254::new();
+        let payload = &bytes[2..];
+        match bytes[1] {
+            POINT_FLAG_INFINITY => {
+                if !payload.is_empty() {
+                    return Err(PointError::InvalidLength { expected: 2, actual: bytes.len() });
+                }
+                Ok(Self::identity(&curve.modulus))
+            }
+            POINT_FLAG_UNCOMPRESSED => {
+                if payload.len() != 64 {
+                    return Err(PointError::InvalidLength { expected: 66, actual: bytes.len() });
+                }
+                let point = Self {
+                    x: coordinate_from_bytes(&payload[..32], &curve.modulus)?,
+                    y: coordinate_from_bytes(&payload[32..], &curve.modulus)?,
+                    infinity: false,
+                };
+                if !curve.is_on_curve(&point) {
+                    return Err(PointError::NotOnCurve);
+                }
+                Ok(point)
+            }
+            POINT_FLAG_COMPRESSED => Err(PointError::UnsupportedEncoding(bytes[1])),
+            flags => Err(PointError::InvalidFlags(flags)),
+        }
+    }
+}
+
+impl G1Affine {
+    /// Encodes the point as the 64-byte big-endian `x || y` used by the EVM
+    /// `ecAdd`/`ecMul` precompiles, with the point at infinity as all zeros
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        if !self.infinity {
+            bytes[..32].copy_from_slice(&coordinate_to_bytes(&self.x));
+            bytes[32..].copy_from_slice(&coordinate_to_bytes(&self.y));
+        }
+        bytes
+    }
+    
+    /// Decodes the precompile layout, checking that the coordinates are canonical
+    /// and the point is on the curve
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<Self, PointError> {
+        Self::from_uncompressed_with(bytes, &BN254::new())
+    }
+    
+    pub(crate) fn from_uncompressed_with(bytes: &[u8; 64], curve: &BN254) -> Result<Self, PointError> {
+        if bytes.iter().all(|&b| b == 0) {
+            return Ok(Self::identity(&curve.modulus));
+        }
         
-        // Process scalar in windows
-        let mut remaining_scalar = scalar.clone();
-        while !remaining_scalar.is_zero() {
-            let window = (&remaining_scalar & BigUint::from(WINDOW_MASK)).to_u64_digits()[0];
-            if window != 0 {
-                result = result + window_points[window as usize].clone();
+        let point = Self {
+            x: coordinate_from_bytes(&bytes[..32], &curve.modulus)?,
+            y: coordinate_from_bytes(&bytes[32..], &curve.modulus)?,
+            infinity: false,
+        };
+        if !curve.is_on_curve(&point) {
+            return Err(PointError::NotOnCurve);
+        }
+        Ok(point)
+    }
+}
+
+impl G1Affine {
+    /// Encodes the point as 32 bytes: big-endian `x` with flags in the top two bits
+    ///
+    /// Uses the same flag bits as `G2Affine::to_compressed`: bit 7 marks the point at
+    /// infinity and bit 6 is set when `y` is the larger of `y` and `-y`.
+    pub fn to_compressed(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        if self.infinity {
+            bytes[0] = COMPRESSED_FLAG_INFINITY;
+            return bytes;
+        }
+        
+        bytes.copy_from_slice(&coordinate_to_bytes(&self.x));
+        if is_lexicographically_largest(&self.y) {
+            bytes[0] |= COMPRESSED_FLAG_Y_LARGEST;
+        }
+        bytes
+    }
+    
+    /// Decodes a point produced by `to_compressed`
+    ///
+    /// G1 has cofactor 1, so every point on the curve is in the subgroup.
+    pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, PointError> {
+        Self::decompress_with(bytes, &BN254::new())
+    }
+    
+    /// Decodes a point produced by `to_compressed` against an existing curve instance
+    ///
+    /// Returns `None` for any invalid encoding; use `from_compressed` to learn why.
+    pub fn decompress(bytes: &[u8; 32], curve: &BN254) -> Option<Self> {
+        Self::decompress_with(bytes, curve).ok()
+    }
+    
+    /// Decodes a concatenation of 32-byte compressed points
+    ///
+    /// Each chunk is decoded like `from_compressed`, with one square root per point.
+    /// The first point that fails to decode is reported by its index, including a
+    /// trailing chunk shorter than 32 bytes.
+    pub fn from_compressed_batch(bytes: &[u8], curve: &BN254) -> Result<Vec<Self>, PointError> {
+        bytes
+            .chunks(32)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let chunk: &[u8; 32] = chunk.try_into().map_err(|_| PointError::InvalidBatchPoint {
+                    index,
+                    source: Box::new(PointError::InvalidLength { expected: 32, actual: chunk.len() }),
+                })?;
+                Self::decompress_with(chunk, curve).map_err(|err| PointError::InvalidBatchPoint {
+                    index,
+                    source: Box::new(err),
+                })
+            })
+            .collect()
+    }
+    
+    fn decompress_with(bytes: &[u8; 32], curve: &BN254) -> Result<Self, PointError> {
+        let flags = bytes[0] & COMPRESSED_FLAG_MASK;
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !COMPRESSED_FLAG_MASK;
+        
+        if flags & COMPRESSED_FLAG_INFINITY != 0 {
+            if flags != COMPRESSED_FLAG_INFINITY || x_bytes.iter().any(|&b| b != 0) {
+                return Err(PointError::InvalidFlags(bytes[0]));
             }
-            
-            // Shift the window
-            for _ in 0..WINDOW_SIZE {
-                temp = temp.double();
+            return Ok(Self::identity(&curve.modulus));
+        }
+        
+        // y² = x³ + b
+        let x = coordinate_from_bytes(&x_bytes, &curve.modulus)?;
+        let rhs = x.clone() * x.clone() * x.clone() + curve.b.clone();
+        let mut y = rhs.sqrt().ok_or(PointError::NotOnCurve)?;
+        if is_lexicographically_largest(&y) != (flags & COMPRESSED_FLAG_Y_LARGEST != 0) {
+            y.neg_assign();
+        }
+        
+        Ok(Self { x, y, infinity: false })
+    }
+}
+
+impl WindowTable {
+    /// Returns the window width in bits
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    /// Multiplies the base by `scalar`, processing windows from the most significant end
+    pub fn mul(&self, scalar: &BigUint) -> G1Affine {
+        let num_windows = (scalar.bits() as usize).div_ceil(self.width);
+        
+        // Accumulate in Jacobian coordinates and invert once at the end
+        let mut result = G1Projective::identity(&self.points[1].modulus());
+        for window in (0..num_windows).rev() {
+            for _ in 0..self.width {
+                result = result.double();
+            }
+            let digit = window_digit(scalar, window * self.width, self.width);
+            if digit != 0 {
+                result = result.add_mixed(&self.points[digit]);
             }
-            remaining_scalar >>= WINDOW_SIZE;
         }
         
-        result
+        result.to_affine().debug_assert_on_curve()
+    }
+}
+
+/// Below this many terms, independent double-and-add products beat bucketing
+const MSM_NAIVE_THRESHOLD: usize = 32;
+
+/// Up to this many terms a fixed 5-bit window is used
+const MSM_SMALL_THRESHOLD: usize = 256;
+
+/// Projective point operations needed by the shared MSM routines
+trait MsmPoint: Clone + Add<Output = Self> {
+    fn double(&self) -> Self;
+}
+
+impl MsmPoint for G1Projective {
+    fn double(&self) -> Self {
+        G1Projective::double(self)
+    }
+}
+
+impl MsmPoint for G2Projective {
+    fn double(&self) -> Self {
+        G2Projective::double(self)
+    }
+}
+
+/// Pippenger window width for `n` terms, or `None` when naive summation is faster
+///
+/// The `G1 MSM` benchmark covers both sides of each threshold.
+fn msm_window_size(n: usize) -> Option<usize> {
+    if n < MSM_NAIVE_THRESHOLD {
+        None
+    } else if n <= MSM_SMALL_THRESHOLD {
+        Some(5)
+    } else {
+        // Roughly ln(n) + 2 bits per window balances bucket additions against windows
+        Some(n.ilog2() as usize * 69 / 100 + 2)
+    }
+}
+
+/// Dispatches to naive summation or Pippenger by the number of terms
+fn msm_projective<P: MsmPoint>(bases: &[P], scalars: &[BigUint], identity: P) -> P {
+    match msm_window_size(bases.len()) {
+        None => naive_msm(bases, scalars, identity),
+        Some(window_size) => pippenger_msm(bases, scalars, identity, window_size),
+    }
+}
+
+/// Sums one double-and-add product per term
+fn naive_msm<P: MsmPoint>(bases: &[P], scalars: &[BigUint], identity: P) -> P {
+    let mut result = identity.clone();
+    for (base, scalar) in bases.iter().zip(scalars) {
+        let mut term = identity.clone();
+        for i in (0..scalar.bits()).rev() {
+            term = term.double();
+            if scalar.bit(i) {
+                term = term + base.clone();
+            }
+        }
+        result = result + term;
+    }
+    result
+}
+
+/// Pippenger's bucket method
+///
+/// Scalars are split into `window_size`-bit windows; in each window every base is
+/// added once into the bucket for its digit, and the buckets are combined with a
+/// running sum.
+fn pippenger_msm<P: MsmPoint>(bases: &[P], scalars: &[BigUint], identity: P, window_size: usize) -> P {
+    let num_bits = scalars.iter().map(|scalar| scalar.bits()).max().unwrap_or(0) as usize;
+
+    let mut window_sums = Vec::new();
+    for start in (0..num_bits).step_by(window_size) {
+        let mut buckets = vec![identity.clone(); (1 << window_size) - 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let digit = window_digit(scalar, start, window_size);
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].clone() + base.clone();
+            }
+        }
+
+        // sum_{d} d * bucket[d] via a running sum from the top bucket down
+        let mut running = identity.clone();
+        let mut window_sum = identity.clone();
+        for bucket in buckets.into_iter().rev() {
+            running = running + bucket;
+            window_sum = window_sum + running.clone();
+        }
+        window_sums.push(window_sum);
+    }
+
+    let mut result = identity;
+    for window_sum in window_sums.into_iter().rev() {
+        for _ in 0..window_size {
+            result = result.double();
+        }
+        result = result + window_sum;
+    }
+    result
+}
+
+/// Reads `width` bits of `scalar` starting at bit `start`
+fn window_digit(scalar: &BigUint, start: usize, width: usize) -> usize {
+    (0..width)
+        .filter(|&bit| scalar.bit((start + bit) as u64))
+        .fold(0, |digit, bit| digit | (1 << bit))
+}
+
+/// Sums `digits·table` for each `(digits, table)` pair with one shared chain of doublings
+///
+/// `digits` are wNAF digits, least significant first, and `table[i]` holds `(2i + 1)·P`;
+/// negative digits add the negated table entry.
+fn wnaf_accumulate(terms: &[(Vec<i64>, Vec<G1Affine>)], modulus: &BigUint) -> G1Projective {
+    let len = terms.iter().map(|(digits, _)| digits.len()).max().unwrap_or(0);
+    
+    let mut result = G1Projective::identity(modulus);
+    for i in (0..len).rev() {
+        result = result.double();
+        for (digits, table) in terms {
+            match digits.get(i).copied().unwrap_or(0) {
+                0 => {}
+                digit if digit > 0 => result = result.add_mixed(&table[(digit as usize) / 2]),
+                digit => result = result.add_mixed(&-&table[(-digit as usize) / 2]),
+            }
+        }
+    }
+    result
+}
+
+/// Width-`width` non-adjacent form of `scalar`, least significant digit first
+///
+/// Every nonzero digit is odd with absolute value below `2^(width-1)`, and any
+/// `width` consecutive digits contain at most one nonzero.
+fn wnaf_digits(scalar: &BigUint, width: usize) -> Vec<i64> {
+    let modulus = 1i64 << width;
+    let mut k = scalar.clone();
+    let mut digits = Vec::with_capacity(scalar.bits() as usize + 1);
+    
+    while !k.is_zero() {
+        let digit = if k.bit(0) {
+            let low = (k.iter_u64_digits().next().unwrap_or(0) & (modulus as u64 - 1)) as i64;
+            let digit = if low >= modulus / 2 { low - modulus } else { low };
+            if digit > 0 {
+                k -= BigUint::from(digit as u64);
+            } else {
+                k += BigUint::from((-digit) as u64);
+            }
+            digit
+        } else {
+            0
+        };
+        digits.push(digit);
+        k >>= 1;
+    }
+    
+    digits
+}
+
+/// Returns true if `value` is larger than its negation as a canonical integer
+fn is_lexicographically_largest(value: &Fp) -> bool {
+    value.from_montgomery() > (-value.clone()).from_montgomery()
+}
+
+/// Encodes a base field coordinate as 32 big-endian bytes
+fn coordinate_to_bytes(value: &Fp) -> [u8; 32] {
+    let bytes = value.from_montgomery().to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+/// Decodes 32 big-endian bytes into a canonical base field coordinate
+pub(crate) fn coordinate_from_bytes(bytes: &[u8], modulus: &BigUint) -> Result<Fp, PointError> {
+    let value = BigUint::from_bytes_be(bytes);
+    if &value >= modulus {
+        return Err(PointError::NonCanonicalCoordinate);
     }
+    Ok(Fp::new(value, modulus.clone()))
 }
 
 impl Add for G1Affine {
@@ -283,22 +1002,57 @@ impl Add for G1Affine {
             y: y3,
             infinity: false,
         }
+        .debug_assert_on_curve()
     }
 }
 
 impl Neg for G1Affine {
     type Output = Self;
     
-    fn neg(self) -> Self {
+    fn neg(mut self) -> Self {
+        if !self.infinity {
+            self.y.neg_assign();
+        }
+        self
+    }
+}
+
+impl Sub for G1Affine {
+    type Output = Self;
+    
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<'a> Add<&'a G1Affine> for &'a G1Affine {
+    type Output = G1Affine;
+    
+    fn add(self, other: &'a G1Affine) -> G1Affine {
+        // Identity operands are returned without touching the other point
         if self.infinity {
-            return self;
+            return other.clone();
         }
-        
-        Self {
-            x: self.x,
-            y: self.y.neg(),
-            infinity: false,
+        if other.infinity {
+            return self.clone();
         }
+        self.clone() + other.clone()
+    }
+}
+
+impl<'a> Sub<&'a G1Affine> for &'a G1Affine {
+    type Output = G1Affine;
+    
+    fn sub(self, other: &'a G1Affine) -> G1Affine {
+        self + &(-other)
+    }
+}
+
+impl Neg for &G1Affine {
+    type Output = G1Affine;
+    
+    fn neg(self) -> G1Affine {
+        -self.clone()
     }
 }
 
@@ -314,20 +1068,16 @@ impl Mul<u64> for G1Affine {
             return self;
         }
         
-        // Double-and-add algorithm with lazy reduction
-        let mut result = Self::identity(&self.modulus());
-        let mut temp = self.clone();
-        let mut n = scalar;
-        
-        while n > 0 {
-            if n & 1 == 1 {
-                result = result + temp.clone();
+        // Double-and-add in Jacobian coordinates, most significant bit first
+        let mut result = G1Projective::identity(&self.modulus());
+        for i in (0..64 - scalar.leading_zeros()).rev() {
+            result = result.double();
+            if (scalar >> i) & 1 == 1 {
+                result = result.add_mixed(&self);
             }
-            temp = temp.double();
-            n >>= 1;
         }
         
-        result
+        result.to_affine().debug_assert_on_curve()
     }
 }
 
@@ -366,7 +1116,7 @@ impl G2Affine {
         let denominator = two.mul(&self.y);
         
         // Check if denominator is zero to avoid division by zero
-        if denominator.c0.is_zero() && denominator.c1.is_zero() {
+        if denominator.is_zero() {
             return Self::identity(&self.x.c0.modulus());
         }
         
@@ -397,37 +1147,161 @@ impl G2Affine {
         const WINDOW_SIZE: usize = 4;
         const WINDOW_MASK: u64 = (1 << WINDOW_SIZE) - 1;
         
-        // Precompute points for each window
+        // Precompute points for each window, normalised to affine with one inversion each
         let mut window_points = vec![Self::identity(&self.x.c0.modulus()); 1 << WINDOW_SIZE];
+        let mut multiple = G2Projective::from_affine(self);
         window_points[1] = self.clone();
-        for i in 2..(1 << WINDOW_SIZE) {
-            window_points[i] = window_points[i-1].clone() + self.clone();
+        for point in window_points.iter_mut().skip(2) {
+            multiple = multiple.add_mixed(self);
+            *point = multiple.to_affine();
         }
         
-        let mut result = Self::identity(&self.x.c0.modulus());
-        let mut temp = self.clone();
-        
-        // Process scalar in windows
+        // Split the scalar into windows, least significant first
+        let mut windows = Vec::new();
         let mut remaining_scalar = scalar.clone();
         while !remaining_scalar.is_zero() {
-            let window = (&remaining_scalar & BigUint::from(WINDOW_MASK)).to_u64_digits()[0];
-            if window != 0 {
-                result = result + window_points[window as usize].clone();
-            }
-            
-            // Shift the window
+            let window = (&remaining_scalar & BigUint::from(WINDOW_MASK)).to_u64_digits();
+            windows.push(window.first().copied().unwrap_or(0) as usize);
+            remaining_scalar >>= WINDOW_SIZE;
+        }
+        
+        // Process windows from the most significant end in Jacobian coordinates
+        let mut result = G2Projective::identity(&self.x.c0.modulus());
+        for &window in windows.iter().rev() {
             for _ in 0..WINDOW_SIZE {
-                temp = temp.double();
+                result = result.double();
+            }
+            if window != 0 {
+                result = result.add_mixed(&window_points[window]);
             }
-            remaining_scalar >>= WINDOW_SIZE;
         }
         
-        result
+        result.to_affine()
     }
-}
 
-impl Add for G2Affine {
-    type Output = Self;
+    /// Multi-scalar multiplication over G2, with the same size-based choice as `G1Affine::msm`
+    ///
+    /// Empty input and bases at infinity behave as in `G1Affine::msm`.
+    pub fn msm(bases: &[Self], scalars: &[BigUint]) -> Self {
+        assert_eq!(bases.len(), scalars.len(), "Bases and scalars must have the same length");
+
+        let modulus = match bases.first() {
+            Some(base) => base.x.c0.modulus(),
+            None => return Self::identity(&BN254::new().modulus),
+        };
+
+        let bases: Vec<G2Projective> = bases.iter().map(G2Projective::from_affine).collect();
+        msm_projective(&bases, scalars, G2Projective::identity(&modulus)).to_affine()
+    }
+}
+
+impl G2Affine {
+    /// Encodes the point as 64 bytes: big-endian `x.c1 || x.c0` with flags in the top two bits
+    ///
+    /// Bit 7 of the first byte marks the point at infinity and bit 6 is set when `y`
+    /// is the lexicographically larger of the two roots. The field modulus is below
+    /// 2^254, so both bits are always free.
+    pub fn to_compressed(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        if self.infinity {
+            bytes[0] = COMPRESSED_FLAG_INFINITY;
+            return bytes;
+        }
+        
+        bytes[..32].copy_from_slice(&coordinate_to_bytes(&self.x.c1));
+        bytes[32..].copy_from_slice(&coordinate_to_bytes(&self.x.c0));
+        if self.y.sign() {
+            bytes[0] |= COMPRESSED_FLAG_Y_LARGEST;
+        }
+        bytes
+    }
+    
+    /// Compresses the point to 64 bytes; shorthand for `to_compressed`
+    pub fn compress(&self) -> [u8; 64] {
+        self.to_compressed()
+    }
+    
+    /// Decodes a point produced by `compress`, checking that it is on the twist and in the subgroup
+    ///
+    /// Returns `None` for any invalid encoding; use `from_compressed` to learn why.
+    pub fn decompress(bytes: &[u8; 64]) -> Option<Self> {
+        Self::from_compressed(bytes).ok()
+    }
+    
+    /// Decodes a compressed point, checking that it is on the twist and in the subgroup
+    pub fn from_compressed(bytes: &[u8; 64]) -> Result<Self, PointError> {
+        let point = Self::from_compressed_unchecked(bytes)?;
+        if !point.is_torsion_free() {
+            return Err(PointError::NotInSubgroup);
+        }
+        Ok(point)
+    }
+    
+    /// Decodes a compressed point without the subgroup check
+    ///
+    /// The result is on the twist but may lie outside the prime-order subgroup.
+    /// Only use this for trusted data, such as points from a verified setup file,
+    /// where the subgroup check (a full scalar multiplication) would dominate load time.
+    pub fn from_compressed_unchecked(bytes: &[u8; 64]) -> Result<Self, PointError> {
+        let curve = BN254::new();
+        let flags = bytes[0] & COMPRESSED_FLAG_MASK;
+        let mut x_bytes = *bytes;
+        x_bytes[0] &= !COMPRESSED_FLAG_MASK;
+        
+        if flags & COMPRESSED_FLAG_INFINITY != 0 {
+            if flags != COMPRESSED_FLAG_INFINITY || x_bytes.iter().any(|&b| b != 0) {
+                return Err(PointError::InvalidFlags(bytes[0]));
+            }
+            return Ok(Self::identity(&curve.modulus));
+        }
+        
+        let x = Fp2::new(
+            coordinate_from_bytes(&x_bytes[32..], &curve.modulus)?,
+            coordinate_from_bytes(&x_bytes[..32], &curve.modulus)?,
+        );
+        
+        // y² = x³ + b'
+        let rhs = x.square().mul(&x).add(&curve.twist_constant_b());
+        let mut y = rhs.sqrt().ok_or(PointError::NotOnCurve)?;
+        if flags & COMPRESSED_FLAG_Y_LARGEST != 0 {
+            y = y.neg();
+        }
+        
+        Ok(Self { x, y, infinity: false })
+    }
+    
+    /// Applies the untwist-Frobenius-twist endomorphism `ψ(x, y) = (x̄·ξ^((p-1)/3), ȳ·ξ^((p-1)/2))`
+    pub fn psi(&self) -> Self {
+        if self.infinity {
+            return self.clone();
+        }
+        let (x_coeff, y_coeff, _) = &*TWIST_FROBENIUS;
+        Self {
+            x: self.x.frobenius(1).mul(x_coeff),
+            y: self.y.frobenius(1).mul(y_coeff),
+            infinity: false,
+        }
+    }
+    
+    /// Maps a point on the twist into the prime-order subgroup
+    ///
+    /// Uses the Fuentes-Castañeda et al. map `[u]Q + ψ([3u]Q) + ψ²([u]Q) + ψ³(Q)`,
+    /// which is a multiple of the cofactor `h2` on the twist but needs only a 63-bit
+    /// scalar multiplication instead of multiplying by `h2` itself.
+    pub fn clear_cofactor(&self) -> Self {
+        let u_q = self.clone() * BN_PARAMETER_U;
+        let three_u_q = u_q.double() + u_q.clone();
+        u_q.clone() + three_u_q.psi() + u_q.psi().psi() + self.psi().psi().psi()
+    }
+    
+    /// Returns true if the point (assumed on the twist) lies in the order-`r` subgroup
+    pub(crate) fn is_torsion_free(&self) -> bool {
+        self.psi() == self.windowed_mul(&PSI_SCALAR)
+    }
+}
+
+impl Add for G2Affine {
+    type Output = Self;
     
     fn add(self, other: Self) -> Self {
         // Check for identity points
@@ -454,7 +1328,7 @@ impl Add for G2Affine {
         let x_diff = other.x.sub(&self.x);
         
         // Check if x_diff is zero to avoid division by zero
-        if x_diff.c0.is_zero() && x_diff.c1.is_zero() {
+        if x_diff.is_zero() {
             return Self::identity(&self.x.c0.modulus());
         }
         
@@ -495,23 +1369,589 @@ impl Neg for G2Affine {
     }
 }
 
+impl Sub for G2Affine {
+    type Output = Self;
+    
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<'a> Add<&'a G2Affine> for &'a G2Affine {
+    type Output = G2Affine;
+    
+    fn add(self, other: &'a G2Affine) -> G2Affine {
+        // Identity operands are returned without touching the other point
+        if self.infinity {
+            return other.clone();
+        }
+        if other.infinity {
+            return self.clone();
+        }
+        self.clone() + other.clone()
+    }
+}
+
+impl<'a> Sub<&'a G2Affine> for &'a G2Affine {
+    type Output = G2Affine;
+    
+    fn sub(self, other: &'a G2Affine) -> G2Affine {
+        self + &(-other)
+    }
+}
+
+impl Neg for &G2Affine {
+    type Output = G2Affine;
+    
+    fn neg(self) -> G2Affine {
+        -self.clone()
+    }
+}
+
 impl Mul<u64> for G2Affine {
     type Output = Self;
     
     fn mul(self, scalar: u64) -> Self {
-        let mut result = Self::identity(&self.x.c0.modulus());
-        let mut temp = self;
-        let mut s = scalar;
-        
-        while s > 0 {
-            if s & 1 == 1 {
-                result = result + temp.clone();
+        // Double-and-add in Jacobian coordinates, most significant bit first
+        let mut result = G2Projective::identity(&self.x.c0.modulus());
+        for i in (0..64 - scalar.leading_zeros()).rev() {
+            result = result.double();
+            if (scalar >> i) & 1 == 1 {
+                result = result.add_mixed(&self);
             }
-            temp = temp.double();
-            s >>= 1;
         }
         
-        result
+        result.to_affine()
+    }
+}
+
+impl G1Projective {
+    /// Creates the identity point (`Z = 0`)
+    pub fn identity(modulus: &BigUint) -> Self {
+        Self {
+            x: Fp::new(BigUint::from(1u32), modulus.clone()),
+            y: Fp::new(BigUint::from(1u32), modulus.clone()),
+            z: Fp::new(BigUint::from(0u32), modulus.clone()),
+        }
+    }
+    
+    /// Returns true if this is the identity point
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+    
+    /// Get the modulus of the field
+    pub fn modulus(&self) -> BigUint {
+        self.x.modulus()
+    }
+    
+    /// Converts an affine point, setting `Z = 1`
+    pub fn from_affine(point: &G1Affine) -> Self {
+        if point.infinity {
+            return Self::identity(&point.modulus());
+        }
+        
+        Self {
+            x: point.x.clone(),
+            y: point.y.clone(),
+            z: Fp::new(BigUint::from(1u32), point.modulus()),
+        }
+    }
+    
+    /// Converts to affine coordinates with a single inversion
+    pub fn to_affine(&self) -> G1Affine {
+        let z_inv = match self.z.inverse() {
+            Some(inv) => inv,
+            None => return G1Affine::identity(&self.modulus()),
+        };
+        
+        let z_inv2 = Field::square(&z_inv);
+        let z_inv3 = z_inv2.clone() * z_inv;
+        
+        G1Affine {
+            x: self.x.clone() * z_inv2,
+            y: self.y.clone() * z_inv3,
+            infinity: false,
+        }
+    }
+    
+    /// Converts many points to affine with a single shared inversion
+    ///
+    /// Uses Montgomery's trick: invert the product of all `Z`, then peel off each
+    /// inverse with two multiplications. Points at infinity are skipped.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<G1Affine> {
+        let finite: Vec<&Self> = points.iter().filter(|p| !p.is_identity()).collect();
+        let Some(first) = finite.first() else {
+            return points.iter().map(Self::to_affine).collect();
+        };
+        
+        // prefix[i] = Z_0 · ... · Z_(i-1)
+        let mut prefix = Vec::with_capacity(finite.len());
+        let mut acc = Fp::new(BigUint::from(1u32), first.modulus());
+        for p in &finite {
+            prefix.push(acc.clone());
+            acc = acc * p.z.clone();
+        }
+        
+        let mut inv = acc.inverse().expect("non-identity points have nonzero Z");
+        let mut z_invs = vec![inv.clone(); finite.len()];
+        for (i, p) in finite.iter().enumerate().rev() {
+            z_invs[i] = inv.clone() * prefix[i].clone();
+            inv = inv * p.z.clone();
+        }
+        
+        let mut z_invs = z_invs.into_iter();
+        points
+            .iter()
+            .map(|p| {
+                if p.is_identity() {
+                    return G1Affine::identity(&p.modulus());
+                }
+                let z_inv = z_invs.next().unwrap();
+                let z_inv2 = Field::square(&z_inv);
+                G1Affine {
+                    x: p.x.clone() * z_inv2.clone(),
+                    y: p.y.clone() * (z_inv2 * z_inv),
+                    infinity: false,
+                }
+            })
+            .collect()
+    }
+    
+    /// Point doubling without inversion (dbl-2009-l, a = 0)
+    pub fn double(&self) -> Self {
+        if self.is_identity() || self.y.is_zero() {
+            return Self::identity(&self.modulus());
+        }
+        
+        let a = Field::square(&self.x);
+        let b = Field::square(&self.y);
+        let c = Field::square(&b);
+        
+        // D = 2((X + B)² - A - C)
+        let mut d = Field::square(&(self.x.clone() + b)) - a.clone() - c.clone();
+        d.double_in_place();
+        
+        // E = 3A, F = E²
+        let mut e = a;
+        e.triple_in_place();
+        let f = Field::square(&e);
+        
+        // X3 = F - 2D
+        let mut d2 = d.clone();
+        d2.double_in_place();
+        let x3 = f - d2;
+        
+        // Y3 = E(D - X3) - 8C
+        let mut c8 = c;
+        c8.mul8_in_place();
+        let y3 = e * (d - x3.clone()) - c8;
+        
+        // Z3 = 2YZ
+        let mut z3 = self.y.clone() * self.z.clone();
+        z3.double_in_place();
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+    
+    /// Adds an affine point without inversion (madd-2007-bl)
+    ///
+    /// With `Z2 = 1` implied, this saves several multiplications over `Add`, which
+    /// makes it the cheaper step when accumulating against a table of affine points.
+    pub fn add_mixed(&self, other: &G1Affine) -> Self {
+        if other.infinity {
+            return self.clone();
+        }
+        if self.is_identity() {
+            return Self::from_affine(other);
+        }
+        
+        let z1z1 = Field::square(&self.z);
+        let u2 = other.x.clone() * z1z1.clone();
+        let s2 = other.y.clone() * self.z.clone() * z1z1.clone();
+        
+        if u2 == self.x {
+            // Same x: either the same point or inverses
+            return if s2 == self.y {
+                self.double()
+            } else {
+                Self::identity(&self.modulus())
+            };
+        }
+        
+        let h = u2 - self.x.clone();
+        let hh = Field::square(&h);
+        let mut i = hh.clone();
+        i.double_in_place();
+        i.double_in_place();
+        let j = h.clone() * i.clone();
+        let r = s2 - self.y.clone();
+        let r = r.clone() + r;
+        let v = self.x.clone() * i;
+        
+        // X3 = r² - J - 2V
+        let x3 = Field::square(&r) - j.clone() - v.clone() - v.clone();
+        
+        // Y3 = r(V - X3) - 2 Y1 J
+        let y1j = self.y.clone() * j;
+        let y3 = r * (v - x3.clone()) - y1j.clone() - y1j;
+        
+        // Z3 = (Z1 + H)² - Z1Z1 - HH
+        let z3 = Field::square(&(self.z.clone() + h)) - z1z1 - hh;
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+    
+    /// Complete addition (Renes–Costello–Batina 2015, Algorithm 7 with `a = 0`)
+    ///
+    /// The same sequence of field operations handles every input, including
+    /// `P + P`, `P + (-P)` and the identity, so there are no data-dependent
+    /// branches. The formulas work in homogeneous coordinates, so the operands are
+    /// mapped from Jacobian form and the result mapped back.
+    pub fn add_complete(&self, other: &Self) -> Self {
+        let (x1, y1, z1) = self.to_homogeneous();
+        let (x2, y2, z2) = other.to_homogeneous();
+        
+        let t0 = x1.clone() * x2.clone();
+        let t1 = y1.clone() * y2.clone();
+        let t2 = z1.clone() * z2.clone();
+        let t3 = (x1.clone() + y1.clone()) * (x2.clone() + y2.clone()) - (t0.clone() + t1.clone());
+        let t4 = (y1 + z1.clone()) * (y2 + z2.clone()) - (t1.clone() + t2.clone());
+        let y3 = (x1 + z1) * (x2 + z2) - (t0.clone() + t2.clone());
+        
+        // t0 = 3·X1X2, t2 = 3b·Z1Z2
+        let mut t0 = t0;
+        t0.triple_in_place();
+        let t2 = mul_by_b3(&t2);
+        let z3 = t1.clone() + t2.clone();
+        let t1 = t1 - t2;
+        let y3 = mul_by_b3(&y3);
+        
+        let x3 = t3.clone() * t1.clone() - t4.clone() * y3.clone();
+        let y3 = t1 * z3.clone() + y3 * t0.clone();
+        let z3 = z3 * t4 + t0 * t3;
+        
+        Self::from_homogeneous(x3, y3, z3)
+    }
+    
+    /// Complete doubling (Renes–Costello–Batina 2015, Algorithm 9 with `a = 0`)
+    pub fn double_complete(&self) -> Self {
+        let (x, y, z) = self.to_homogeneous();
+        
+        let t0 = Field::square(&y);
+        let mut z3 = t0.clone();
+        z3.mul8_in_place();
+        let t1 = y.clone() * z.clone();
+        let t2 = mul_by_b3(&Field::square(&z));
+        let x3 = t2.clone() * z3.clone();
+        let y3 = t0.clone() + t2.clone();
+        let z3 = t1 * z3;
+        
+        let mut t2_3 = t2;
+        t2_3.triple_in_place();
+        let t0 = t0 - t2_3;
+        let y3 = t0.clone() * y3 + x3;
+        let mut x3 = t0 * (x * y);
+        x3.double_in_place();
+        
+        Self::from_homogeneous(x3, y3, z3)
+    }
+    
+    /// Maps Jacobian `(X, Y, Z)` to homogeneous `(XZ, Y, Z³)`; the identity becomes `(0, 1, 0)`
+    fn to_homogeneous(&self) -> (Fp, Fp, Fp) {
+        let z2 = Field::square(&self.z);
+        (self.x.clone() * self.z.clone(), self.y.clone(), z2 * self.z.clone())
+    }
+    
+    /// Maps homogeneous `(X, Y, Z)` back to Jacobian `(XZ, YZ², Z)`
+    ///
+    /// For the identity `(0, Y, 0)` the `Y` coordinate is kept rather than scaled to
+    /// zero, so the result remains a valid input to the complete formulas.
+    fn from_homogeneous(x: Fp, y: Fp, z: Fp) -> Self {
+        let at_infinity = Fp::from_u64(z.is_zero() as u64, z.modulus());
+        let y_scale = Field::square(&z) + at_infinity;
+        Self { x: x * z.clone(), y: y * y_scale, z }
+    }
+}
+
+/// Multiplies by `3b = 9`, the constant of the complete formulas for `y² = x³ + 3`
+fn mul_by_b3(value: &Fp) -> Fp {
+    let mut eight = value.clone();
+    eight.mul8_in_place();
+    eight + value.clone()
+}
+
+impl Add for G1Projective {
+    type Output = Self;
+    
+    /// Point addition without inversion (add-2007-bl)
+    fn add(self, other: Self) -> Self {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+        
+        let z1z1 = Field::square(&self.z);
+        let z2z2 = Field::square(&other.z);
+        let u1 = self.x.clone() * z2z2.clone();
+        let u2 = other.x.clone() * z1z1.clone();
+        let s1 = self.y.clone() * other.z.clone() * z2z2.clone();
+        let s2 = other.y.clone() * self.z.clone() * z1z1.clone();
+        
+        if u1 == u2 {
+            // Same x: either the same point or inverses
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::identity(&self.modulus())
+            };
+        }
+        
+        let h = u2 - u1.clone();
+        let i = Field::square(&(h.clone() + h.clone()));
+        let j = h.clone() * i.clone();
+        let r = s2 - s1.clone();
+        let r = r.clone() + r;
+        let v = u1 * i;
+        
+        // X3 = r² - J - 2V
+        let x3 = Field::square(&r) - j.clone() - v.clone() - v.clone();
+        
+        // Y3 = r(V - X3) - 2 S1 J
+        let s1j = s1 * j;
+        let y3 = r * (v - x3.clone()) - s1j.clone() - s1j;
+        
+        // Z3 = ((Z1 + Z2)² - Z1Z1 - Z2Z2) H
+        let z3 = (Field::square(&(self.z + other.z)) - z1z1 - z2z2) * h;
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl PartialEq for G1Projective {
+    /// Compares the represented affine points without inverting
+    ///
+    /// `(X1, Y1, Z1)` and `(X2, Y2, Z2)` are equal when `X1·Z2² = X2·Z1²` and
+    /// `Y1·Z2³ = Y2·Z1³`, so rescaled representations compare equal.
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_identity() || other.is_identity() {
+            return self.is_identity() && other.is_identity();
+        }
+        
+        let z1_sq = Field::square(&self.z);
+        let z2_sq = Field::square(&other.z);
+        if self.x.clone() * z2_sq.clone() != other.x.clone() * z1_sq.clone() {
+            return false;
+        }
+        
+        self.y.clone() * z2_sq * other.z.clone() == other.y.clone() * z1_sq * self.z.clone()
+    }
+}
+
+impl Eq for G1Projective {}
+
+impl From<G1Affine> for G1Projective {
+    fn from(point: G1Affine) -> Self {
+        Self::from_affine(&point)
+    }
+}
+
+impl From<G1Projective> for G1Affine {
+    fn from(point: G1Projective) -> Self {
+        point.to_affine()
+    }
+}
+
+impl G2Projective {
+    /// Creates the identity point (`Z = 0`)
+    pub fn identity(modulus: &BigUint) -> Self {
+        Self {
+            x: Fp2::one(modulus),
+            y: Fp2::one(modulus),
+            z: Fp2::zero(modulus),
+        }
+    }
+    
+    /// Returns true if this is the identity point
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+    
+    /// Get the modulus of the base field
+    pub fn modulus(&self) -> BigUint {
+        self.x.c0.modulus()
+    }
+    
+    /// Converts an affine point, setting `Z = 1`
+    pub fn from_affine(point: &G2Affine) -> Self {
+        let modulus = point.x.c0.modulus();
+        if point.infinity {
+            return Self::identity(&modulus);
+        }
+        
+        Self {
+            x: point.x.clone(),
+            y: point.y.clone(),
+            z: Fp2::one(&modulus),
+        }
+    }
+    
+    /// Converts to affine coordinates with a single inversion
+    pub fn to_affine(&self) -> G2Affine {
+        let z_inv = match self.z.inverse() {
+            Some(inv) => inv,
+            None => return G2Affine::identity(&self.modulus()),
+        };
+        
+        let z_inv2 = z_inv.square();
+        let z_inv3 = z_inv2.mul(&z_inv);
+        
+        G2Affine {
+            x: self.x.mul(&z_inv2),
+            y: self.y.mul(&z_inv3),
+            infinity: false,
+        }
+    }
+    
+    /// Point doubling without inversion (dbl-2009-l, a = 0)
+    pub fn double(&self) -> Self {
+        if self.is_identity() || (self.y.is_zero()) {
+            return Self::identity(&self.modulus());
+        }
+        
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        
+        // D = 2((X + B)² - A - C)
+        let d = self.x.add(&b).square().sub(&a).sub(&c);
+        let d = d.add(&d);
+        
+        // E = 3A, F = E²
+        let e = a.add(&a).add(&a);
+        let f = e.square();
+        
+        // X3 = F - 2D
+        let x3 = f.sub(&d).sub(&d);
+        
+        // Y3 = E(D - X3) - 8C
+        let c2 = c.add(&c);
+        let c4 = c2.add(&c2);
+        let c8 = c4.add(&c4);
+        let y3 = e.mul(&d.sub(&x3)).sub(&c8);
+        
+        // Z3 = 2YZ
+        let yz = self.y.mul(&self.z);
+        let z3 = yz.add(&yz);
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+    
+    /// Adds an affine point without inversion (madd-2007-bl), as `G1Projective::add_mixed`
+    pub fn add_mixed(&self, other: &G2Affine) -> Self {
+        if other.infinity {
+            return self.clone();
+        }
+        if self.is_identity() {
+            return Self::from_affine(other);
+        }
+        
+        let z1z1 = self.z.square();
+        let u2 = other.x.mul(&z1z1);
+        let s2 = other.y.mul(&self.z).mul(&z1z1);
+        
+        if u2 == self.x {
+            // Same x: either the same point or inverses
+            return if s2 == self.y {
+                self.double()
+            } else {
+                Self::identity(&self.modulus())
+            };
+        }
+        
+        let h = u2.sub(&self.x);
+        let hh = h.square();
+        let i = hh.add(&hh);
+        let i = i.add(&i);
+        let j = h.mul(&i);
+        let r = s2.sub(&self.y);
+        let r = r.add(&r);
+        let v = self.x.mul(&i);
+        
+        // X3 = r² - J - 2V
+        let x3 = r.square().sub(&j).sub(&v).sub(&v);
+        
+        // Y3 = r(V - X3) - 2 Y1 J
+        let y1j = self.y.mul(&j);
+        let y3 = r.mul(&v.sub(&x3)).sub(&y1j).sub(&y1j);
+        
+        // Z3 = (Z1 + H)² - Z1Z1 - HH
+        let z3 = self.z.add(&h).square().sub(&z1z1).sub(&hh);
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl Add for G2Projective {
+    type Output = Self;
+    
+    /// Point addition without inversion (add-2007-bl)
+    fn add(self, other: Self) -> Self {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+        
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x.mul(&z2z2);
+        let u2 = other.x.mul(&z1z1);
+        let s1 = self.y.mul(&other.z).mul(&z2z2);
+        let s2 = other.y.mul(&self.z).mul(&z1z1);
+        
+        if u1 == u2 {
+            // Same x: either the same point or inverses
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::identity(&self.modulus())
+            };
+        }
+        
+        let h = u2.sub(&u1);
+        let i = h.add(&h).square();
+        let j = h.mul(&i);
+        let r = s2.sub(&s1);
+        let r = r.add(&r);
+        let v = u1.mul(&i);
+        
+        // X3 = r² - J - 2V
+        let x3 = r.square().sub(&j).sub(&v).sub(&v);
+        
+        // Y3 = r(V - X3) - 2 S1 J
+        let s1j = s1.mul(&j);
+        let y3 = r.mul(&v.sub(&x3)).sub(&s1j).sub(&s1j);
+        
+        // Z3 = ((Z1 + Z2)² - Z1Z1 - Z2Z2) H
+        let z3 = self.z.add(&other.z).square().sub(&z1z1).sub(&z2z2).mul(&h);
+        
+        Self { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl From<G2Affine> for G2Projective {
+    fn from(point: G2Affine) -> Self {
+        Self::from_affine(&point)
+    }
+}
+
+impl From<G2Projective> for G2Affine {
+    fn from(point: G2Projective) -> Self {
+        point.to_affine()
     }
 }
 
@@ -537,6 +1977,16 @@ impl Fp2 {
         Self { c0: one, c1: zero }
     }
     
+    /// Returns true if this is zero
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+    
+    /// Returns true if this is one
+    pub fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero()
+    }
+    
     /// Multiply two Fp2 elements
     pub fn mul(&self, other: &Self) -> Self {
         // (a + bu) * (c + du) = (ac - bd) + (ad + bc)u
@@ -607,23 +2057,113 @@ impl Fp2 {
         Self { c0, c1 }
     }
     
-    /// Compute the inverse of an Fp2 element
-    pub fn inverse(&self) -> Option<Self> {
-        // (a + bu)⁻¹ = (a - bu) / (a² + b²)
-        let a = &self.c0;
-        let b = &self.c1;
-        
+    /// Multiply by a base field element
+    pub fn mul_by_fp(&self, other: &Fp) -> Self {
+        Self {
+            c0: self.c0.clone() * other.clone(),
+            c1: self.c1.clone() * other.clone(),
+        }
+    }
+    
+    /// Multiply by the sextic non-residue ξ = 9 + u used to build the tower
+    pub fn mul_by_nonresidue(&self) -> Self {
+        // (a + bu)(9 + u) = (9a - b) + (a + 9b)u
+        Self {
+            c0: self.c0.mul_u64(9) - self.c1.clone(),
+            c1: self.c0.clone() + self.c1.mul_u64(9),
+        }
+    }
+    
+    /// Raises the element to an arbitrary-width power using square-and-multiply
+    pub fn pow_biguint(&self, exp: &BigUint) -> Self {
+        let mut result = Self::one(&self.c0.modulus());
+        for i in (0..exp.bits()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+    
+    /// Applies the Frobenius endomorphism `x -> x^(p^power)`
+    ///
+    /// Since `u^p = -u`, odd powers conjugate the element and even powers fix it.
+    pub fn frobenius(&self, power: usize) -> Self {
+        if power.is_multiple_of(2) {
+            return self.clone();
+        }
+        Self {
+            c0: self.c0.clone(),
+            c1: self.c1.clone().neg(),
+        }
+    }
+    
+    /// Computes the square root with `sign() == false`, or `None` if the element is not a square
+    ///
+    /// Uses the norm: if `a + bu = (x + yu)²` then `a² + b²` is a square `t²` in Fp
+    /// and `x² = (a ± t) / 2`, `y = b / 2x`.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.sqrt_unsigned()?;
+        Some(if root.sign() { root.neg() } else { root })
+    }
+    
+    /// Computes either square root, whichever the norm method lands on
+    fn sqrt_unsigned(&self) -> Option<Self> {
+        let modulus = self.c0.modulus();
+        let zero = Fp::new(BigUint::from(0u32), modulus.clone());
+        
+        if self.c1.is_zero() {
+            // -1 is a non-residue, so exactly one of a and -a has a root in Fp
+            if let Some(root) = self.c0.sqrt() {
+                return Some(Self::new(root, zero));
+            }
+            return self.c0.clone().neg().sqrt().map(|root| Self::new(zero, root));
+        }
+        
+        let norm = self.c0.clone() * self.c0.clone() + self.c1.clone() * self.c1.clone();
+        let t = norm.sqrt()?;
+        let half = Fp::new(BigUint::from(2u32), modulus).inverse()?;
+        
+        let x = match ((self.c0.clone() + t.clone()) * half.clone()).sqrt() {
+            Some(x) => x,
+            None => ((self.c0.clone() - t) * half).sqrt()?,
+        };
+        let y = self.c1.clone() * (x.clone() + x.clone()).inverse()?;
+        
+        Some(Self::new(x, y))
+    }
+    
+    /// Returns true if the element is larger than its negation, comparing `(c1, c0)` canonically
+    ///
+    /// This is the sign convention for Fp2: exactly one of `x` and `-x` has sign
+    /// `true` unless `x` is zero. Compressed G2 points store it in the y flag.
+    pub fn sign(&self) -> bool {
+        let neg = self.neg();
+        (self.c1.from_montgomery(), self.c0.from_montgomery())
+            > (neg.c1.from_montgomery(), neg.c0.from_montgomery())
+    }
+    
+    /// Compute the inverse of an Fp2 element
+    pub fn inverse(&self) -> Option<Self> {
+        // (a + bu)⁻¹ = (a - bu) / (a² + b²)
+        let a = &self.c0;
+        let b = &self.c1;
+        
         let a2 = a.clone() * a.clone();
         let b2 = b.clone() * b.clone();
         let denom = a2 + b2;
         
-        match denom.inverse() {
-            Some(inv) => Some(Self {
-                c0: a.clone() * inv.clone(),
-                c1: (b.clone() * inv).neg(),
-            }),
-            None => None,
-        }
+        denom.inverse().map(|inv| Self {
+            c0: a.clone() * inv.clone(),
+            c1: (b.clone() * inv).neg(),
+        })
+    }
+}
+
+impl fmt::Display for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}*u", self.c0.from_montgomery(), self.c1.from_montgomery())
     }
 }
 
@@ -782,6 +2322,48 @@ mod tests {
         assert!(!g.y.c1.is_zero());
     }
     
+    #[test]
+    fn test_g2_multiples_on_twist_and_in_subgroup() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        let fp2 = |c0: &str, c1: &str| Fp2::new(
+            Fp::new(BigUint::from_str(c0).unwrap(), curve.modulus.clone()),
+            Fp::new(BigUint::from_str(c1).unwrap(), curve.modulus.clone()),
+        );
+        
+        for scalar in [2u64, 3, 7, 12345, u64::MAX] {
+            let p = g.windowed_mul(&BigUint::from(scalar));
+            assert!(curve.is_on_curve_g2(&p), "{}·G2 is not on the twist", scalar);
+            assert!(p.is_torsion_free(), "{}·G2 is not in the subgroup", scalar);
+        }
+        
+        // 2·G2 and 12345·G2 from an independent affine implementation over Fp2
+        let reference_2 = G2Affine {
+            x: fp2(
+                "18029695676650738226693292988307914797657423701064905010927197838374790804409",
+                "14583779054894525174450323658765874724019480979794335525732096752006891875705",
+            ),
+            y: fp2(
+                "2140229616977736810657479771656733941598412651537078903776637920509952744750",
+                "11474861747383700316476719153975578001603231366361248090558603872215261634898",
+            ),
+            infinity: false,
+        };
+        let reference_12345 = G2Affine {
+            x: fp2(
+                "3192987715530305580946656034371093193867421631544099513284129268236929013789",
+                "448602512351820053537578350330943944299093775480635140124264317645075730570",
+            ),
+            y: fp2(
+                "13584593069657188777564781531889207910929617773138379471284502551228322987148",
+                "3049665309719187264176258658325297011616596223981498538539541783749822515442",
+            ),
+            infinity: false,
+        };
+        assert_eq!(g.double(), reference_2);
+        assert_eq!(g.windowed_mul(&BigUint::from(12345u32)), reference_12345);
+    }
+    
     #[test]
     fn test_windowed_scalar_multiplication_g1() {
         let curve = BN254::new();
@@ -825,4 +2407,870 @@ mod tests {
             assert!(curve.is_on_curve_g2(&p1));
         }
     }
+    
+    #[test]
+    fn test_multi_mul_g1() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let bases = vec![g.clone(), g.double(), g.clone() * 7];
+        let scalars = vec![
+            BigUint::from(5u32),
+            BigUint::from_str("123456789").unwrap(),
+            BigUint::from(0u32),
+        ];
+        
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(G1Affine::identity(&curve.modulus), |acc, (base, scalar)| {
+                acc + base.windowed_mul(scalar)
+            });
+        
+        let result = G1Affine::multi_mul(&bases, &scalars);
+        assert_eq!(result, expected);
+        assert!(curve.is_on_curve(&result));
+        
+        // The empty sum is the identity
+        assert!(G1Affine::multi_mul(&[], &[]).infinity);
+    }
+    
+    #[test]
+    fn test_window_table_reuse() {
+        let curve = BN254::new();
+        let base = curve.g1_generator() * 7;
+        
+        let scalars = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(255u32),
+            BigUint::from_str("123456789012345678901234567890").unwrap(),
+            Scalar::modulus() - 1u32,
+        ];
+        for width in [1, 3, 4, 5] {
+            let table = base.precompute_window(width);
+            assert_eq!(table.width(), width);
+            for scalar in &scalars {
+                assert_eq!(table.mul(scalar), base.windowed_mul(scalar));
+            }
+        }
+    }
+    
+    #[test]
+    fn test_mul_bits_matches_windowed_mul() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let mut rng = rand::thread_rng();
+        let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+        let scalar = BigUint::from_bytes_le(&bytes) % Scalar::modulus();
+        
+        let bits: Vec<bool> = (0..scalar.bits()).rev().map(|i| scalar.bit(i)).collect();
+        assert_eq!(g.mul_bits(&bits), g.windowed_mul(&scalar));
+        
+        // Leading zeros don't change the result; no bits give the identity
+        let padded: Vec<bool> = [false; 3].iter().chain(bits.iter()).copied().collect();
+        assert_eq!(g.mul_bits(&padded), g.windowed_mul(&scalar));
+        assert!(g.mul_bits(&[]).infinity);
+    }
+    
+    #[test]
+    fn test_msm_matches_naive_sum() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let mut rng = rand::thread_rng();
+        
+        // Naive, 5-bit and size-derived window regimes, either side of each switch
+        for size in [0usize, 1, 2, 31, 32, 40, 256, 257] {
+            let bases: Vec<G1Affine> = (0..size as u64).map(|i| g.clone() * (i + 1)).collect();
+            let scalars: Vec<BigUint> = (0..size)
+                .map(|_| {
+                    let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+                    BigUint::from_bytes_le(&bytes) % Scalar::modulus()
+                })
+                .collect();
+            
+            // Base i is (i + 1) * g, so the whole sum is a single multiple of g
+            let combined = scalars
+                .iter()
+                .enumerate()
+                .fold(BigUint::from(0u32), |acc, (i, scalar)| acc + scalar * (i + 1));
+            let naive = g.windowed_mul(&(combined % Scalar::modulus()));
+            assert_eq!(G1Affine::msm(&bases, &scalars), naive);
+        }
+        
+        // Zero scalars and repeated bases
+        let bases = vec![g.clone(), g.clone(), g.clone()];
+        let scalars = vec![BigUint::from(0u32), BigUint::from(7u32), BigUint::from(7u32)];
+        assert_eq!(G1Affine::msm(&bases, &scalars), g.clone() * 14);
+    }
+    
+    #[test]
+    fn test_msm_matches_naive_loop_with_infinity() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let identity = G1Affine::identity(&curve.modulus);
+        let mut rng = rand::thread_rng();
+        
+        // 50 independent random points, with every seventh one at infinity
+        let random_scalar = |rng: &mut rand::rngs::ThreadRng| {
+            let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(rng)).collect();
+            BigUint::from_bytes_le(&bytes) % Scalar::modulus()
+        };
+        let bases: Vec<G1Affine> = (0..50)
+            .map(|i| if i % 7 == 0 { identity.clone() } else { g.windowed_mul(&random_scalar(&mut rng)) })
+            .collect();
+        let scalars: Vec<BigUint> = (0..50).map(|_| random_scalar(&mut rng)).collect();
+        
+        let naive = bases
+            .iter()
+            .zip(&scalars)
+            .fold(identity.clone(), |acc, (base, scalar)| acc + base.windowed_mul(scalar));
+        assert_eq!(G1Affine::msm(&bases, &scalars), naive);
+        
+        let all_infinity = vec![identity.clone(); 40];
+        assert!(G1Affine::msm(&all_infinity, &scalars[..40]).infinity);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Bases and scalars must have the same length")]
+    fn test_msm_rejects_mismatched_lengths() {
+        let g = BN254::new().g1_generator();
+        G1Affine::msm(&[g.clone(), g], &[BigUint::from(1u32)]);
+    }
+    
+    /// `k · G1` in the EVM precompile layout, pinned from an independent affine
+    /// implementation; 2G and 3G also appear in the EIP-196 test suite
+    const PRECOMPILE_G1_VECTORS: [(u64, &str); 5] = [
+        (1, "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002"),
+        (2, "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+             15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+        (3, "0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0\
+             2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261"),
+        (9, "039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869\
+             073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98"),
+        (0xdeadbeef, "1fd9bf9c6c9fc892f0b4f856657cd9309f43e2f1cfa3ed4724c40bd74ea13803\
+                      18ee06de0e49deaf292d55f31fd13e603489f81bfa4ec6f2443ba2274621703f"),
+    ];
+    
+    fn hex_to_64(s: &str) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+    
+    #[test]
+    fn test_uncompressed_matches_precompile_vectors() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        
+        for (k, hex) in PRECOMPILE_G1_VECTORS {
+            let bytes = hex_to_64(hex);
+            let point = g.windowed_mul(&BigUint::from(k));
+            assert_eq!(point.to_uncompressed(), bytes, "encoding of {k}·G");
+            assert_eq!(G1Affine::from_uncompressed(&bytes).unwrap(), point);
+        }
+        
+        // The precompiles encode the point at infinity as 64 zero bytes
+        let identity = G1Affine::identity(&curve.modulus);
+        assert_eq!(identity.to_uncompressed(), [0u8; 64]);
+        assert!(G1Affine::from_uncompressed(&[0u8; 64]).unwrap().infinity);
+        
+        // Off-curve and non-canonical inputs are rejected
+        let mut off_curve = hex_to_64(PRECOMPILE_G1_VECTORS[0].1);
+        off_curve[63] = 3;
+        assert_eq!(G1Affine::from_uncompressed(&off_curve), Err(PointError::NotOnCurve));
+        let mut non_canonical = [0xffu8; 64];
+        non_canonical[32..].copy_from_slice(&[0u8; 32]);
+        assert_eq!(G1Affine::from_uncompressed(&non_canonical), Err(PointError::NonCanonicalCoordinate));
+    }
+    
+    #[test]
+    fn test_complete_formulas_match_affine() {
+        let curve = BN254::new();
+        let p = curve.g1_generator() * 5;
+        let q = curve.g1_generator() * 11;
+        let o = G1Affine::identity(&curve.modulus);
+        let proj = |point: &G1Affine| G1Projective::from_affine(point);
+        
+        let cases = [
+            (p.clone(), q.clone()),
+            (p.clone(), p.clone()),
+            (p.clone(), -p.clone()),
+            (p.clone(), o.clone()),
+            (o.clone(), p.clone()),
+            (o.clone(), o.clone()),
+        ];
+        for (a, b) in cases {
+            let expected = a.clone() + b.clone();
+            assert_eq!(proj(&a).add_complete(&proj(&b)).to_affine(), expected);
+        }
+        
+        for point in [p.clone(), o.clone()] {
+            assert_eq!(proj(&point).double_complete().to_affine(), point.double());
+        }
+        
+        // Identity results feed back into the formulas, and Jacobian inputs with Z ≠ 1 work
+        let zero = proj(&p).add_complete(&proj(&-p.clone()));
+        assert!(zero.is_identity());
+        assert_eq!(zero.add_complete(&proj(&q)).to_affine(), q);
+        assert_eq!(zero.double_complete().to_affine(), o);
+        let scaled = proj(&p).double();
+        assert_eq!(scaled.add_complete(&proj(&q)).to_affine(), p.double() + q.clone());
+        assert_eq!(scaled.double_complete(), scaled.double());
+    }
+    
+    #[test]
+    fn test_cached_instance_matches_fresh_build() {
+        assert_eq!(*BN254::bn254(), BN254::build());
+        assert_eq!(BN254::new(), BN254::build());
+        assert!(std::ptr::eq(BN254::bn254(), BN254::bn254()));
+    }
+    
+    #[test]
+    fn test_debug_on_curve_check_accepts_valid_arithmetic() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let scalar = BigUint::from(0xdeadbeefu64);
+        
+        let doubled = g.double();
+        let sum = doubled.clone() + g.clone();
+        assert_eq!(sum, g.clone() * 3);
+        assert_eq!(g.windowed_mul(&scalar), g.clone() * 0xdeadbeef);
+        
+        let bits: Vec<bool> = (0..scalar.bits()).rev().map(|i| scalar.bit(i)).collect();
+        assert!(curve.is_on_curve(&g.mul_bits(&bits)));
+    }
+    
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "G1 arithmetic produced a point off the curve")]
+    fn test_debug_on_curve_check_catches_corrupted_point() {
+        let curve = BN254::new();
+        let mut corrupted = curve.g1_generator().double();
+        corrupted.y = corrupted.y + Fp::new(BigUint::from(1u32), curve.modulus.clone());
+        
+        // Doubling keeps the point on whichever curve y² - x³ puts it, which is no longer BN254
+        let _ = corrupted.double();
+    }
+    
+    #[test]
+    fn test_wnaf_mul_matches_windowed_mul() {
+        let g = BN254::new().g1_generator();
+        let scalars = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(7u32),
+            BigUint::from(0xdeadbeefu64),
+            BigUint::from(u64::MAX),
+            Scalar::modulus() - BigUint::from(1u32),
+        ];
+        
+        for width in 2..=6 {
+            for scalar in &scalars {
+                assert_eq!(g.wnaf_mul(scalar, width), g.windowed_mul(scalar), "width {}", width);
+                
+                // Digits recombine to the scalar and respect the non-adjacency bound
+                let digits = wnaf_digits(scalar, width);
+                let (pos, neg) = digits.iter().enumerate().fold(
+                    (BigUint::from(0u32), BigUint::from(0u32)),
+                    |(pos, neg), (i, &d)| match d.cmp(&0) {
+                        std::cmp::Ordering::Greater => (pos + (BigUint::from(d as u64) << i), neg),
+                        std::cmp::Ordering::Less => (pos, neg + (BigUint::from((-d) as u64) << i)),
+                        std::cmp::Ordering::Equal => (pos, neg),
+                    },
+                );
+                assert_eq!(pos - neg, scalar.clone());
+                for window in digits.windows(width) {
+                    assert!(window.iter().filter(|&&d| d != 0).count() <= 1);
+                }
+                assert!(digits.iter().all(|&d| d == 0 || (d % 2 != 0 && d.abs() < 1 << (width - 1))));
+            }
+        }
+        
+        assert!(G1Affine::identity(&g.modulus()).wnaf_mul(&BigUint::from(5u32), 4).infinity);
+    }
+    
+    #[test]
+    fn test_glv_mul_matches_windowed_mul() {
+        let g = BN254::new().g1_generator();
+        
+        // φ acts as multiplication by λ
+        assert_eq!(g.endomorphism(), g.windowed_mul(&glv::lambda()));
+        
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            glv::lambda(),
+            Scalar::modulus() - BigUint::from(1u32),
+        ];
+        for _ in 0..8 {
+            let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+            scalars.push(BigUint::from_bytes_le(&bytes) % Scalar::modulus());
+        }
+        
+        let p = g.clone() * 12345;
+        for scalar in &scalars {
+            assert_eq!(p.glv_mul(scalar), p.windowed_mul(scalar));
+        }
+        assert!(G1Affine::identity(&g.modulus()).glv_mul(&BigUint::from(7u32)).infinity);
+    }
+    
+    #[test]
+    fn test_projective_double_and_mixed_add_match_affine() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let identity = G1Affine::identity(&curve.modulus);
+        
+        let g_proj = G1Projective::from_affine(&g);
+        assert_eq!(g_proj.double().to_affine(), g.double());
+        
+        // Mixed addition against a Jacobian point with Z ≠ 1
+        let p = g_proj.double().double();
+        for q in [g.clone(), g.clone() * 5, -(g.clone() * 4), g.clone() * 4, identity.clone()] {
+            let expected = p.to_affine() + q.clone();
+            assert_eq!(p.add_mixed(&q).to_affine(), expected);
+            assert_eq!(p.add_mixed(&q), p.clone() + G1Projective::from_affine(&q));
+        }
+        assert_eq!(G1Projective::identity(&curve.modulus).add_mixed(&g).to_affine(), g);
+        
+        // Scalar multiplication accumulates projectively and agrees with the affine ladder
+        let affine_ladder = (0..10).fold(identity, |acc, _| acc + g.clone());
+        assert_eq!(g.clone() * 10, affine_ladder);
+        assert_eq!(g.windowed_mul(&BigUint::from(10u32)), affine_ladder);
+    }
+    
+    #[test]
+    fn test_mul_scalar_matches_windowed_mul() {
+        let g = BN254::new().g1_generator();
+        let p = g.clone() * 31337;
+        let mut rng = rand::thread_rng();
+        
+        let mut scalars = vec![
+            Scalar::new(BigUint::from(0u32)),
+            Scalar::new(BigUint::from(1u32)),
+            Scalar::new(glv::lambda()),
+            Scalar::new(Scalar::modulus() - BigUint::from(1u32)),
+        ];
+        for _ in 0..8 {
+            let bytes: Vec<u8> = (0..32).map(|_| rand::Rng::gen(&mut rng)).collect();
+            scalars.push(Scalar::new(BigUint::from_bytes_le(&bytes)));
+        }
+        
+        for s in &scalars {
+            assert_eq!(p.mul_scalar(s), p.windowed_mul(&s.to_biguint()));
+        }
+        assert!(G1Affine::identity(&g.modulus()).mul_scalar(&scalars[5]).infinity);
+    }
+    
+    #[test]
+    fn test_batch_to_affine_matches_individual() {
+        let curve = BN254::new();
+        let g = G1Projective::from_affine(&curve.g1_generator());
+        let identity = G1Projective::identity(&curve.modulus);
+        
+        // Mixed Z values, including the identity in the middle
+        let points = vec![g.clone(), g.double(), identity.clone(), g.double().double() + g.clone(), g.double().double()];
+        let expected: Vec<G1Affine> = points.iter().map(G1Projective::to_affine).collect();
+        assert_eq!(G1Projective::batch_to_affine(&points), expected);
+        
+        assert!(G1Projective::batch_to_affine(&[]).is_empty());
+        assert!(G1Projective::batch_to_affine(&[identity])[0].infinity);
+    }
+    
+    #[test]
+    fn test_batch_normalize_with_identities() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let gp = G1Projective::from_affine(&g);
+        
+        // g + (-g) reaches Z = 0 through the addition formulas rather than `identity`
+        let cancelled = gp.clone() + G1Projective::from_affine(&-g.clone());
+        assert!(cancelled.is_identity());
+        
+        let points = vec![
+            G1Projective::identity(&curve.modulus),
+            gp.double(),
+            cancelled,
+            gp.double().add_mixed(&g),
+            G1Projective::identity(&curve.modulus),
+        ];
+        let affine = G1Affine::batch_normalize(&points);
+        assert_eq!(affine.len(), points.len());
+        assert!(affine[0].infinity && affine[2].infinity && affine[4].infinity);
+        assert_eq!(affine[1], g.clone() * 2);
+        assert_eq!(affine[3], g.clone() * 3);
+        
+        let identities = vec![G1Projective::identity(&curve.modulus); 3];
+        assert!(G1Affine::batch_normalize(&identities).iter().all(|p| p.infinity));
+    }
+    
+    #[test]
+    fn test_reference_operators_match_owned() {
+        let curve = BN254::new();
+        let p = curve.g1_generator() * 3;
+        let q = curve.g1_generator() * 5;
+        let identity = G1Affine::identity(&curve.modulus);
+        
+        assert_eq!(&p + &q, p.clone() + q.clone());
+        assert_eq!(&q - &p, curve.g1_generator() * 2);
+        assert_eq!(-&p, -p.clone());
+        assert_eq!(&p + &identity, p);
+        assert!((&p - &p).infinity);
+        
+        // Accumulating over borrowed points needs no per-element clone
+        let points = [p.clone(), q.clone(), p.clone()];
+        let sum = points.iter().fold(identity, |acc, point| &acc + point);
+        assert_eq!(sum, curve.g1_generator() * 11);
+        
+        let a = curve.g2_generator() * 3;
+        let b = curve.g2_generator() * 5;
+        assert_eq!(&a + &b, a.clone() + b.clone());
+        assert_eq!(&b - &a, curve.g2_generator() * 2);
+        assert_eq!(-&a, -a.clone());
+        let g2_sum = [a.clone(), b.clone()]
+            .iter()
+            .fold(G2Affine::identity(&curve.modulus), |acc, point| &acc + point);
+        assert_eq!(g2_sum, curve.g2_generator() * 8);
+    }
+    
+    #[test]
+    fn test_g2_msm_matches_naive_sum() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        let mut rng = rand::thread_rng();
+        
+        for size in [0usize, 1, 2, 5, 33] {
+            let bases: Vec<G2Affine> = (0..size as u64).map(|i| g.clone() * (i + 1)).collect();
+            let scalars: Vec<BigUint> = (0..size)
+                .map(|_| BigUint::from(rand::Rng::gen::<u64>(&mut rng)))
+                .collect();
+            
+            let combined = scalars
+                .iter()
+                .enumerate()
+                .fold(BigUint::from(0u32), |acc, (i, scalar)| acc + scalar * (i + 1));
+            assert_eq!(G2Affine::msm(&bases, &scalars), g.windowed_mul(&combined));
+        }
+    }
+    
+    #[test]
+    fn test_g2_msm_with_infinity_and_zero_scalars() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        let identity = G2Affine::identity(&curve.modulus);
+        
+        // Infinity bases and zero scalars land in buckets that must stay neutral
+        for size in [2usize, 3, 8, 40] {
+            let bases: Vec<G2Affine> = (0..size as u64)
+                .map(|i| if i % 3 == 1 { identity.clone() } else { g.clone() * (i + 2) })
+                .collect();
+            let scalars: Vec<BigUint> = (0..size as u64)
+                .map(|i| BigUint::from(if i % 4 == 3 { 0 } else { 1000 * i + 17 }))
+                .collect();
+            
+            let naive = bases
+                .iter()
+                .zip(&scalars)
+                .fold(identity.clone(), |acc, (base, scalar)| acc + base.windowed_mul(scalar));
+            assert_eq!(G2Affine::msm(&bases, &scalars), naive);
+        }
+        
+        let all_infinity = vec![identity.clone(); 5];
+        let scalars: Vec<BigUint> = (1..=5u32).map(BigUint::from).collect();
+        assert!(G2Affine::msm(&all_infinity, &scalars).infinity);
+    }
+    
+    #[test]
+    fn test_g2_projective_scalar_mul_matches_affine() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        let identity = G2Affine::identity(&curve.modulus);
+        
+        // Affine reference ladder: 1·G, 2·G, ..., 20·G
+        let mut affine_multiples = vec![identity.clone()];
+        for i in 1..=20 {
+            affine_multiples.push(affine_multiples[i - 1].clone() + g.clone());
+        }
+        
+        for k in [0u64, 1, 2, 3, 7, 16, 20] {
+            let expected = &affine_multiples[k as usize];
+            assert_eq!(&(g.clone() * k), expected);
+            assert_eq!(&g.windowed_mul(&BigUint::from(k)), expected);
+        }
+        
+        // Mixed addition against a Jacobian point with Z ≠ 1
+        let p = G2Projective::from_affine(&g).double().double();
+        for q in [g.clone(), affine_multiples[5].clone(), -affine_multiples[4].clone(), affine_multiples[4].clone(), identity] {
+            assert_eq!(p.add_mixed(&q).to_affine(), p.to_affine() + q.clone());
+        }
+        
+        let scalar = BigUint::from(0xdeadbeefu64);
+        assert_eq!(g.windowed_mul(&scalar), g.clone() * 0xdeadbeef);
+        assert!(curve.is_on_curve_g2(&g.windowed_mul(&scalar)));
+    }
+    
+    #[test]
+    fn test_twist_constant_b_matches_computed() {
+        let curve = BN254::new();
+        let computed = curve.compute_twist_constant_b();
+        
+        assert_eq!(curve.twist_constant_b(), computed);
+        
+        // b' * (9 + u) = 3
+        let xi = Fp2::new(
+            Fp::new(BigUint::from(9u32), curve.modulus.clone()),
+            Fp::new(BigUint::from(1u32), curve.modulus.clone()),
+        );
+        let three = Fp2::new(curve.b.clone(), Fp::new(BigUint::from(0u32), curve.modulus.clone()));
+        assert_eq!(computed.mul(&xi), three);
+    }
+    
+    #[test]
+    fn test_g1_projective_round_trip() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let mut rng = rand::thread_rng();
+        
+        let points = vec![
+            g.clone(),
+            g.windowed_mul(&BigUint::from(rand::Rng::gen::<u64>(&mut rng))),
+            G1Affine::identity(&curve.modulus),
+        ];
+        
+        for p in points {
+            assert_eq!(G1Affine::from(G1Projective::from(p.clone())), p);
+        }
+        
+        // Projective arithmetic agrees with affine arithmetic
+        let p = G1Projective::from(g.clone());
+        assert_eq!(G1Affine::from(p.double()), g.double());
+        assert_eq!(G1Affine::from(p.clone() + p.double()), g.clone() * 3);
+        assert!((p.clone() + G1Projective::from(-g.clone())).is_identity());
+    }
+    
+    #[test]
+    fn test_g1_projective_equality() {
+        let curve = BN254::new();
+        let g = G1Projective::from(curve.g1_generator());
+        
+        // (λ²X, λ³Y, λZ) represents the same point
+        let lambda = Fp::new(BigUint::from(123456789u64), curve.modulus.clone());
+        let lambda_sq = Field::square(&lambda);
+        let rescaled = G1Projective {
+            x: g.x.clone() * lambda_sq.clone(),
+            y: g.y.clone() * lambda_sq * lambda.clone(),
+            z: g.z.clone() * lambda,
+        };
+        assert_eq!(rescaled, g);
+        assert_eq!(g.double() + g.clone(), g.clone() + g.double());
+        
+        // Distinct points and the identity compare unequal
+        assert_ne!(g.double(), g);
+        assert_ne!(G1Projective::from(-curve.g1_generator()), g);
+        assert_ne!(G1Projective::identity(&curve.modulus), g);
+        assert_eq!(G1Projective::identity(&curve.modulus), g.clone() + G1Projective::from(-curve.g1_generator()));
+    }
+    
+    #[test]
+    fn test_g2_projective_round_trip() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        
+        let points = vec![
+            g.clone(),
+            g.clone() * 12345,
+            G2Affine::identity(&curve.modulus),
+        ];
+        
+        for p in points {
+            assert_eq!(G2Affine::from(G2Projective::from(p.clone())), p);
+        }
+        
+        let p = G2Projective::from(g.clone());
+        assert_eq!(G2Affine::from(p.double()), g.double());
+        assert_eq!(G2Affine::from(p.clone() + p.double()), g.clone() * 3);
+    }
+    
+    #[test]
+    fn test_g1_versioned_encoding() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        
+        for p in [g.clone(), g.clone() * 5, G1Affine::identity(&curve.modulus)] {
+            let bytes = p.to_bytes_versioned();
+            assert_eq!(bytes[0], POINT_ENCODING_VERSION);
+            assert_eq!(G1Affine::from_bytes_versioned(&bytes), Ok(p));
+        }
+        
+        // Unknown version byte
+        let mut bytes = g.to_bytes_versioned();
+        bytes[0] = 0xff;
+        assert_eq!(G1Affine::from_bytes_versioned(&bytes), Err(PointError::UnknownVersion(0xff)));
+        
+        // Unknown flags and off-curve points
+        let mut bytes = g.to_bytes_versioned();
+        bytes[1] = 0x80;
+        assert_eq!(G1Affine::from_bytes_versioned(&bytes), Err(PointError::InvalidFlags(0x80)));
+        
+        let mut bytes = g.to_bytes_versioned();
+        bytes[65] ^= 1;
+        assert_eq!(G1Affine::from_bytes_versioned(&bytes), Err(PointError::NotOnCurve));
+    }
+    
+    #[test]
+    fn test_fp2_sqrt() {
+        let curve = BN254::new();
+        let g2 = curve.g2_generator();
+        
+        for a in [g2.x.clone(), g2.y.clone(), Fp2::one(&curve.modulus), Fp2::one(&curve.modulus).neg()] {
+            let square = a.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == a || root == a.neg());
+            assert!(!root.sign());
+            assert_ne!(a.sign(), a.neg().sign());
+        }
+        
+        // ξ = 9 + u is a non-residue in Fp2
+        let xi = Fp2::one(&curve.modulus).mul_by_nonresidue();
+        assert!(xi.sqrt().is_none());
+    }
+    
+    #[test]
+    fn test_g2_compressed_round_trip() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        
+        for p in [g.clone(), g.clone() * 7, -g.clone(), G2Affine::identity(&curve.modulus)] {
+            let bytes = p.to_compressed();
+            assert_eq!(G2Affine::from_compressed(&bytes), Ok(p.clone()));
+            assert_eq!(G2Affine::from_compressed_unchecked(&bytes), Ok(p));
+        }
+        
+        // Both flags set is not a valid encoding
+        let mut bytes = G2Affine::identity(&curve.modulus).to_compressed();
+        bytes[0] |= 0x40;
+        assert_eq!(G2Affine::from_compressed(&bytes), Err(PointError::InvalidFlags(0xc0)));
+        
+        // Non-canonical coordinate
+        let mut bytes = g.to_compressed();
+        let modulus_bytes = curve.modulus.to_bytes_be();
+        bytes[32..].copy_from_slice(&modulus_bytes);
+        assert_eq!(G2Affine::from_compressed(&bytes), Err(PointError::NonCanonicalCoordinate));
+    }
+    
+    #[test]
+    fn test_g2_compress_decompress() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        
+        let bytes = g.compress();
+        assert_eq!(bytes, g.to_compressed());
+        assert_eq!(G2Affine::decompress(&bytes), Some(g.clone()));
+        assert!(curve.is_on_curve_g2(&G2Affine::decompress(&bytes).unwrap()));
+        
+        // x = 0 has no y on the twist, and a coordinate equal to the modulus is malformed
+        assert_eq!(G2Affine::decompress(&[0u8; 64]), None);
+        let mut bytes = g.compress();
+        bytes[32..].copy_from_slice(&curve.modulus.to_bytes_be());
+        assert_eq!(G2Affine::decompress(&bytes), None);
+    }
+    
+    #[test]
+    fn test_g2_decompression_picks_signed_root() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        
+        // The generator and its negation share x and differ only in the sign flag
+        let bytes = g.to_compressed();
+        let neg_bytes = (-g.clone()).to_compressed();
+        assert_eq!(bytes[1..], neg_bytes[1..]);
+        assert_eq!(bytes[0] & COMPRESSED_FLAG_Y_LARGEST != 0, g.y.sign());
+        assert_ne!(bytes[0], neg_bytes[0]);
+        
+        let decoded = G2Affine::from_compressed(&bytes).unwrap();
+        assert_eq!(decoded.x, g.x);
+        assert_eq!(decoded.y, g.y);
+        assert_eq!(G2Affine::from_compressed(&neg_bytes).unwrap().y, g.y.neg());
+    }
+    
+    #[test]
+    fn test_g2_compressed_subgroup_check() {
+        let curve = BN254::new();
+        
+        // Find a point on the twist by trying small x; the cofactor is huge, so it is
+        // almost certainly outside the subgroup
+        let mut bytes = [0u8; 64];
+        let point = (1u8..)
+            .find_map(|c0| {
+                bytes[63] = c0;
+                G2Affine::from_compressed_unchecked(&bytes).ok()
+            })
+            .unwrap();
+        
+        assert!(curve.is_on_curve_g2(&point));
+        assert_eq!(G2Affine::from_compressed(&bytes), Err(PointError::NotInSubgroup));
+        
+        // x = 0 gives y² = b', which has no root
+        let zero = [0u8; 64];
+        assert_eq!(G2Affine::from_compressed_unchecked(&zero), Err(PointError::NotOnCurve));
+    }
+    
+    #[test]
+    fn test_cofactors() {
+        let curve = BN254::new();
+        assert_eq!(BN254::cofactor_g1(), BigUint::from(1u32));
+        assert_eq!(BN254::cofactor_g2(), &curve.modulus * 2u32 - BN254::group_order());
+        
+        // The twist order r · h2 annihilates a point outside the subgroup, r alone doesn't
+        let mut bytes = [0u8; 64];
+        let point = (1u8..)
+            .find_map(|c0| {
+                bytes[63] = c0;
+                G2Affine::from_compressed_unchecked(&bytes).ok()
+            })
+            .unwrap();
+        let mul = |scalar: &BigUint| {
+            let base = G2Projective::from_affine(&point);
+            (0..scalar.bits()).rev().fold(G2Projective::identity(&curve.modulus), |acc, i| {
+                let acc = acc.double();
+                if scalar.bit(i) { acc + base.clone() } else { acc }
+            })
+        };
+        let twist_order = BN254::group_order() * BN254::cofactor_g2();
+        assert!(mul(&twist_order).is_identity());
+        assert!(!mul(&BN254::group_order()).is_identity());
+        
+        // Clearing the cofactor lands in the subgroup
+        assert!(mul(&BN254::cofactor_g2()).to_affine().is_torsion_free());
+    }
+    
+    #[test]
+    fn test_is_in_subgroup_g2() {
+        let curve = BN254::new();
+        let g = curve.g2_generator();
+        let full_order_check = |point: &G2Affine| point.windowed_mul(&BN254::group_order()).infinity;
+        
+        assert!(curve.is_in_subgroup_g2(&g));
+        assert!(curve.is_in_subgroup_g2(&(g.clone() * 12345)));
+        assert!(curve.is_in_subgroup_g2(&G2Affine::identity(&curve.modulus)));
+        assert_eq!(g.psi(), g.windowed_mul(&BigUint::from_str(PSI_EIGENVALUE).unwrap()));
+        
+        // Points on the twist from small x are almost surely outside the subgroup, and
+        // the ψ check must agree with [r]Q for each of them
+        let mut bytes = [0u8; 64];
+        let mut outside = 0;
+        for c0 in 1u8..40 {
+            bytes[63] = c0;
+            if let Ok(point) = G2Affine::from_compressed_unchecked(&bytes) {
+                assert!(curve.is_on_curve_g2(&point));
+                assert_eq!(curve.is_in_subgroup_g2(&point), full_order_check(&point));
+                outside += usize::from(!full_order_check(&point));
+            }
+        }
+        assert!(outside > 0);
+        
+        // Off the curve is never in the subgroup
+        let mut off_curve = g.clone();
+        off_curve.y = off_curve.y.add(&Fp2::one(&curve.modulus));
+        assert!(!curve.is_in_subgroup_g2(&off_curve));
+    }
+    
+    #[test]
+    fn test_g2_clear_cofactor() {
+        let curve = BN254::new();
+        
+        // Find points on the twist outside the subgroup and map them in
+        let mut bytes = [0u8; 64];
+        let mut cleared = 0;
+        for c0 in 1u8..20 {
+            bytes[63] = c0;
+            let Ok(point) = G2Affine::from_compressed_unchecked(&bytes) else { continue };
+            assert!(!curve.is_in_subgroup_g2(&point));
+            
+            let mapped = point.clear_cofactor();
+            assert!(!mapped.infinity);
+            assert!(curve.is_in_subgroup_g2(&mapped));
+            assert!(mapped.windowed_mul(&BN254::group_order()).infinity);
+            cleared += 1;
+        }
+        assert!(cleared > 0);
+        
+        // Subgroup points stay in the subgroup and the identity is fixed
+        let g = curve.g2_generator();
+        assert!(curve.is_in_subgroup_g2(&g.clear_cofactor()));
+        assert!(G2Affine::identity(&curve.modulus).clear_cofactor().infinity);
+    }
+    
+    #[test]
+    fn test_fp2_frobenius() {
+        let curve = BN254::new();
+        let a = curve.g2_generator().x;
+        
+        let a_p = a.pow_biguint(&curve.modulus);
+        
+        assert_eq!(a.frobenius(1), a_p);
+        assert_ne!(a.frobenius(1), a);
+        assert_eq!(a.frobenius(2), a);
+        assert_eq!(a.frobenius(3), a_p);
+    }
+    
+    #[test]
+    fn test_g1_compressed_round_trip() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        
+        for p in [g.clone(), g.double(), -g.clone(), G1Affine::identity(&curve.modulus)] {
+            assert_eq!(G1Affine::from_compressed(&p.to_compressed()), Ok(p));
+        }
+        
+        // x = 0 gives y² = 3, which has no root
+        assert_eq!(G1Affine::from_compressed(&[0u8; 32]), Err(PointError::NotOnCurve));
+    }
+    
+    #[test]
+    fn test_g1_decompress_with_curve() {
+        let curve = BN254::bn254();
+        let g = curve.g1_generator();
+        
+        for p in [g.clone(), g.double(), G1Affine::identity(&curve.modulus)] {
+            let bytes = p.to_compressed();
+            assert_eq!(G1Affine::decompress(&bytes, curve), Some(p));
+        }
+        
+        // Off-curve x and bad flags both give None
+        assert_eq!(G1Affine::decompress(&[0u8; 32], curve), None);
+        let mut bytes = g.to_compressed();
+        bytes[0] |= COMPRESSED_FLAG_INFINITY;
+        assert_eq!(G1Affine::decompress(&bytes, curve), None);
+    }
+    
+    #[test]
+    fn test_g1_from_compressed_batch() {
+        let curve = BN254::new();
+        let g = curve.g1_generator();
+        let points: Vec<G1Affine> = (1..=5u64).map(|k| g.clone() * k).collect();
+        
+        let mut bytes: Vec<u8> = points.iter().flat_map(|p| p.to_compressed()).collect();
+        assert_eq!(G1Affine::from_compressed_batch(&bytes, &curve), Ok(points));
+        
+        // Corrupt the fourth point's x-coordinate so it falls off the curve
+        bytes[3 * 32..4 * 32].copy_from_slice(&[0u8; 32]);
+        assert_eq!(
+            G1Affine::from_compressed_batch(&bytes, &curve),
+            Err(PointError::InvalidBatchPoint { index: 3, source: Box::new(PointError::NotOnCurve) })
+        );
+        
+        // A trailing partial chunk is reported as its own index
+        let bytes: Vec<u8> = g.to_compressed().into_iter().chain([0u8; 5]).collect();
+        assert_eq!(
+            G1Affine::from_compressed_batch(&bytes, &curve),
+            Err(PointError::InvalidBatchPoint {
+                index: 1,
+                source: Box::new(PointError::InvalidLength { expected: 32, actual: 5 }),
+            })
+        );
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,111 @@
+//! GLV scalar decomposition for BN254 G1
+//!
+//! BN254 has an efficient endomorphism `φ(x, y) = (βx, y)` acting on G1 as
+//! multiplication by `λ`, a primitive cube root of unity mod `r`. Splitting a
+//! scalar as `k = k1 + k2·λ` with half-width `k1, k2` halves the number of
+//! doublings in scalar multiplication.
+
+use crate::curve::scalar::Scalar;
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use std::str::FromStr;
+
+/// Eigenvalue of the G1 endomorphism, `λ² + λ + 1 ≡ 0 (mod r)`
+pub const LAMBDA: &str = "4407920970296243842393367215006156084916469457145843978461";
+
+/// Cube root of unity in Fp with `φ(P) = (βx, y) = λ·P` on G1
+pub const BETA: &str = "2203960485148121921418603742825762020974279258880205651966";
+
+/// Short basis `(a1, b1), (a2, b2)` of the lattice `{(a, b) : a + b·λ ≡ 0 (mod r)}`
+const BASIS_A1: &str = "9931322734385697763";
+const BASIS_B1: &str = "-147946756881789319000765030803803410728";
+const BASIS_A2: &str = "147946756881789319010696353538189108491";
+const BASIS_B2: &str = "9931322734385697763";
+
+lazy_static! {
+    static ref GLV_LAMBDA: BigInt = BigInt::from_str(LAMBDA).unwrap();
+    static ref A1: BigInt = BigInt::from_str(BASIS_A1).unwrap();
+    static ref B1: BigInt = BigInt::from_str(BASIS_B1).unwrap();
+    static ref A2: BigInt = BigInt::from_str(BASIS_A2).unwrap();
+    static ref B2: BigInt = BigInt::from_str(BASIS_B2).unwrap();
+}
+
+/// Returns `λ` as an unsigned integer
+pub fn lambda() -> BigUint {
+    GLV_LAMBDA.to_biguint().unwrap()
+}
+
+/// Returns `β` as an unsigned integer
+pub fn beta() -> BigUint {
+    BigUint::from_str(BETA).unwrap()
+}
+
+/// Splits `k` into signed half-width scalars with `k1 + k2·λ ≡ k (mod r)`
+///
+/// Both halves are bounded in absolute value by roughly `sqrt(r)` (about 128 bits).
+pub fn decompose_scalar(k: &BigUint) -> (BigInt, BigInt) {
+    let r = BigInt::from_biguint(Sign::Plus, Scalar::modulus());
+    let k = BigInt::from_biguint(Sign::Plus, k % Scalar::modulus());
+
+    // c1 = round(b2·k / r), c2 = round(-b1·k / r)
+    let c1 = round_div(&(&*B2 * &k), &r);
+    let c2 = round_div(&(-&*B1 * &k), &r);
+
+    let k1 = &k - &c1 * &*A1 - &c2 * &*A2;
+    let k2 = -(&c1 * &*B1) - &c2 * &*B2;
+
+    (k1, k2)
+}
+
+/// Divides and rounds to the nearest integer, for a positive divisor
+fn round_div(numerator: &BigInt, denominator: &BigInt) -> BigInt {
+    let doubled: BigInt = numerator * 2 + denominator;
+    doubled.div_floor(&(denominator * 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_lattice_basis() {
+        let r = BigInt::from_biguint(Sign::Plus, Scalar::modulus());
+
+        // λ is a primitive cube root of unity
+        let lambda = &*GLV_LAMBDA;
+        let cube: BigInt = lambda * lambda + lambda + 1;
+        assert_eq!(cube.mod_floor(&r), BigInt::from(0));
+
+        // Both basis vectors lie in the lattice
+        assert_eq!((&*A1 + &*B1 * lambda).mod_floor(&r), BigInt::from(0));
+        assert_eq!((&*A2 + &*B2 * lambda).mod_floor(&r), BigInt::from(0));
+    }
+
+    #[test]
+    fn test_decompose_scalar() {
+        let r = BigInt::from_biguint(Sign::Plus, Scalar::modulus());
+        let mut rng = rand::thread_rng();
+
+        let mut scalars = vec![
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            Scalar::modulus() - 1u32,
+            lambda(),
+        ];
+        for _ in 0..20 {
+            let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+            scalars.push(BigUint::from_bytes_le(&bytes) % Scalar::modulus());
+        }
+
+        for k in scalars {
+            let (k1, k2) = decompose_scalar(&k);
+            let recombined = (&k1 + &k2 * &*GLV_LAMBDA).mod_floor(&r);
+            assert_eq!(recombined, BigInt::from_biguint(Sign::Plus, k.clone()));
+
+            assert!(k1.magnitude().bits() <= 128);
+            assert!(k2.magnitude().bits() <= 128);
+        }
+    }
+}
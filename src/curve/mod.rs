@@ -1,4 +1,13 @@
 pub mod bn254;
+pub mod glv;
+pub mod pairing;
+pub mod scalar;
+pub mod tower;
+pub mod traits;
 
 // Re-export commonly used types
-pub use bn254::{BN254, G1Affine, G2Affine}; 
\ No newline at end of file
+pub use bn254::{BN254, G1Affine, G1Projective, G2Affine, G2Projective, PointError, WindowTable};
+pub use pairing::{ecpairing_input_parse, final_exponentiation, miller_loop};
+pub use tower::{Fp6, Fp12};
+pub use scalar::Scalar;
+pub use traits::CurveGroup;
\ No newline at end of file
@@ -0,0 +1,312 @@
+//! Optimal ate pairing on BN254
+//!
+//! `e: G1 × G2 → GT ⊂ Fp12*` is computed as a Miller loop over `6u + 2` followed by
+//! the final exponentiation to the power `(p^12 - 1) / r`.
+
+use crate::curve::bn254::{coordinate_from_bytes, Fp2, G1Affine, G2Affine, PointError, BN254, TWIST_FROBENIUS};
+use crate::curve::scalar::Scalar;
+use crate::curve::tower::Fp12;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use std::str::FromStr;
+
+/// Size in bytes of one `(G1, G2)` pair in the `ecPairing` precompile input
+pub const ECPAIRING_PAIR_BYTES: usize = 192;
+
+/// Miller loop length `6u + 2` for the BN parameter `u = 4965661367192848881`
+const ATE_LOOP_COUNT: &str = "29793968203157093288";
+
+lazy_static! {
+    static ref ATE_LOOP: BigUint = BigUint::from_str(ATE_LOOP_COUNT).unwrap();
+
+    /// Hard part of the final exponentiation, `(p^4 - p^2 + 1) / r`
+    static ref HARD_EXPONENT: BigUint = {
+        let p = BN254::new().modulus;
+        let p2 = &p * &p;
+        (&p2 * &p2 - &p2 + 1u32) / Scalar::modulus()
+    };
+}
+
+impl BN254 {
+    /// Computes the optimal ate pairing `e(p, q)`
+    ///
+    /// The inputs are assumed to be valid subgroup points; use `pairing_checked` for
+    /// untrusted inputs. Pairing with the identity gives one.
+    pub fn pairing(&self, p: &G1Affine, q: &G2Affine) -> Fp12 {
+        final_exponentiation(&miller_loop(p, q))
+    }
+
+    /// Computes `e(p, q)` after checking that both points are on their curves and in
+    /// the prime-order subgroups
+    pub fn pairing_checked(&self, p: &G1Affine, q: &G2Affine) -> Result<Fp12, PointError> {
+        // G1 has cofactor 1, so being on the curve is enough
+        if !self.is_on_curve(p) || !self.is_on_curve_g2(q) {
+            return Err(PointError::NotOnCurve);
+        }
+        if !q.is_torsion_free() {
+            return Err(PointError::NotInSubgroup);
+        }
+
+        Ok(self.pairing(p, q))
+    }
+
+    /// Returns true if `∏ e(p_i, q_i) = 1`, the check done by the `ecPairing` precompile
+    ///
+    /// The Miller loops are multiplied together so only one final exponentiation is
+    /// needed. An empty product is one.
+    pub fn pairing_check(&self, pairs: &[(G1Affine, G2Affine)]) -> bool {
+        let f = pairs
+            .iter()
+            .fold(Fp12::one(&self.modulus), |acc, (p, q)| acc.mul(&miller_loop(p, q)));
+        final_exponentiation(&f).is_one()
+    }
+}
+
+/// Parses the input of the `ecPairing` precompile (address 0x08) into point pairs
+///
+/// Every 192-byte chunk is the G1 point as `x || y` followed by the G2 point as
+/// `x.c1 || x.c0 || y.c1 || y.c0` (imaginary part first), each a 32-byte big-endian
+/// coordinate. All-zero coordinates encode the point at infinity. As with the
+/// precompile, points must be on their curves and G2 points in the subgroup; the
+/// first invalid pair is reported by its index.
+pub fn ecpairing_input_parse(bytes: &[u8]) -> Result<Vec<(G1Affine, G2Affine)>, PointError> {
+    if !bytes.len().is_multiple_of(ECPAIRING_PAIR_BYTES) {
+        return Err(PointError::InvalidLength {
+            expected: bytes.len().next_multiple_of(ECPAIRING_PAIR_BYTES),
+            actual: bytes.len(),
+        });
+    }
+
+    let curve = BN254::new();
+    bytes
+        .chunks(ECPAIRING_PAIR_BYTES)
+        .enumerate()
+        .map(|(index, chunk)| {
+            parse_pair(chunk, &curve).map_err(|err| PointError::InvalidBatchPoint {
+                index,
+                source: Box::new(err),
+            })
+        })
+        .collect()
+}
+
+/// Decodes and validates one precompile pair
+fn parse_pair(chunk: &[u8], curve: &BN254) -> Result<(G1Affine, G2Affine), PointError> {
+    let coordinate = |i: usize| coordinate_from_bytes(&chunk[32 * i..32 * (i + 1)], &curve.modulus);
+
+    let p = G1Affine::from_uncompressed_with(chunk[..64].try_into().expect("pair chunk holds a G1 point"), curve)?;
+    let q = if chunk[64..].iter().all(|&b| b == 0) {
+        G2Affine::identity(&curve.modulus)
+    } else {
+        G2Affine {
+            x: Fp2::new(coordinate(3)?, coordinate(2)?),
+            y: Fp2::new(coordinate(5)?, coordinate(4)?),
+            infinity: false,
+        }
+    };
+
+    if !curve.is_on_curve_g2(&q) {
+        return Err(PointError::NotOnCurve);
+    }
+    if !q.infinity && !q.is_torsion_free() {
+        return Err(PointError::NotInSubgroup);
+    }
+    Ok((p, q))
+}
+
+/// Runs the Miller loop `f_{6u+2,Q}(P) · l_{T,π(Q)}(P) · l_{T',-π²(Q)}(P)`
+pub fn miller_loop(p: &G1Affine, q: &G2Affine) -> Fp12 {
+    let modulus = p.modulus();
+    let mut f = Fp12::one(&modulus);
+    if p.infinity || q.infinity {
+        return f;
+    }
+
+    let mut t = q.clone();
+    for i in (0..ATE_LOOP.bits() - 1).rev() {
+        f = f.square();
+        f = line_double(&mut t, p, &f);
+
+        if ATE_LOOP.bit(i) {
+            f = line_add(&mut t, q, p, &f);
+        }
+    }
+
+    // Q1 = π(Q), Q2 = -π²(Q)
+    let (_, _, x_coeff_sq) = &*TWIST_FROBENIUS;
+    let q1 = q.psi();
+    let q2 = G2Affine {
+        x: q.x.mul(x_coeff_sq),
+        y: q.y.clone(),
+        infinity: false,
+    };
+
+    f = line_add(&mut t, &q1, p, &f);
+    line_add(&mut t, &q2, p, &f)
+}
+
+/// Raises a Miller loop output to `(p^12 - 1) / r`
+pub fn final_exponentiation(f: &Fp12) -> Fp12 {
+    // Easy part: f^((p^6 - 1)(p^2 + 1))
+    let f_inv = match f.inverse() {
+        Some(inv) => inv,
+        None => return Fp12::zero(&f.c0.c0.c0.modulus()),
+    };
+    let r = f.conjugate().mul(&f_inv);
+    let r = r.frobenius(2).mul(&r);
+
+    r.pow_biguint(&HARD_EXPONENT)
+}
+
+/// Doubles `t` and multiplies `f` by the tangent line at `t` evaluated at `p`
+fn line_double(t: &mut G2Affine, p: &G1Affine, f: &Fp12) -> Fp12 {
+    // λ = 3x² / 2y on the twist
+    let x_sq = t.x.square();
+    let numerator = x_sq.add(&x_sq).add(&x_sq);
+    let lambda = numerator.mul(&t.y.add(&t.y).inverse().expect("subgroup points have y != 0"));
+
+    let line = evaluate_line(&lambda, t, p);
+    let x3 = lambda.square().sub(&t.x).sub(&t.x);
+    update_point(t, &lambda, x3);
+    f.mul_by_034(&line.0, &line.1, &line.2)
+}
+
+/// Sets `t = t + q` and multiplies `f` by the line through `t` and `q` evaluated at `p`
+fn line_add(t: &mut G2Affine, q: &G2Affine, p: &G1Affine, f: &Fp12) -> Fp12 {
+    // λ = (y_q - y_t) / (x_q - x_t)
+    let lambda = q.y.sub(&t.y).mul(&q.x.sub(&t.x).inverse().expect("t != ±q inside the Miller loop"));
+
+    let line = evaluate_line(&lambda, t, p);
+    let x3 = lambda.square().sub(&t.x).sub(&q.x);
+    update_point(t, &lambda, x3);
+    f.mul_by_034(&line.0, &line.1, &line.2)
+}
+
+/// Moves `t` to the third intersection of the line with slope `λ`, reflected
+fn update_point(t: &mut G2Affine, lambda: &Fp2, x3: Fp2) {
+    // y3 = λ(x_t - x3) - y_t, reusing the slope instead of inverting again
+    t.y = lambda.mul(&t.x.sub(&x3)).sub(&t.y);
+    t.x = x3;
+}
+
+/// Evaluates the line with twist slope `λ` through `t` at `p`
+///
+/// Untwisting `(x, y) -> (x w², y w³)` turns the line into
+/// `y_p - λ x_p w + (λ x_t - y_t) w³`, i.e. coefficients 0, 3 and 4 of the tower.
+fn evaluate_line(lambda: &Fp2, t: &G2Affine, p: &G1Affine) -> (Fp2, Fp2, Fp2) {
+    let zero = Fp2::zero(&p.modulus()).c0;
+    let c0 = Fp2::new(p.y.clone(), zero);
+    let c3 = lambda.mul_by_fp(&p.x).neg();
+    let c4 = lambda.mul(&t.x).sub(&t.y);
+    (c0, c3, c4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairing_bilinearity() {
+        let curve = BN254::new();
+        let p = curve.g1_generator();
+        let q = curve.g2_generator();
+
+        let e = curve.pairing(&p, &q);
+        assert_ne!(e, Fp12::one(&curve.modulus));
+        assert_eq!(e.pow_biguint(&Scalar::modulus()), Fp12::one(&curve.modulus));
+
+        // e(aP, bQ) = e(P, Q)^(ab)
+        let lhs = curve.pairing(&(p.clone() * 3), &(q.clone() * 5));
+        assert_eq!(lhs, e.pow_biguint(&BigUint::from(15u32)));
+
+        // e(P, Q) · e(-P, Q) = 1
+        let inverse = curve.pairing(&-p, &q);
+        assert_eq!(e.mul(&inverse), Fp12::one(&curve.modulus));
+    }
+
+    #[test]
+    fn test_pairing_with_identity() {
+        let curve = BN254::new();
+        let one = Fp12::one(&curve.modulus);
+
+        let p = G1Affine::identity(&curve.modulus);
+        assert_eq!(curve.pairing(&p, &curve.g2_generator()), one);
+
+        let q = G2Affine::identity(&curve.modulus);
+        assert_eq!(curve.pairing(&curve.g1_generator(), &q), one);
+    }
+
+    #[test]
+    fn test_pairing_checked() {
+        let curve = BN254::new();
+        let p = curve.g1_generator();
+        let q = curve.g2_generator();
+
+        assert_eq!(curve.pairing_checked(&p, &q), Ok(curve.pairing(&p, &q)));
+
+        // A twist point outside the subgroup is rejected
+        let mut bytes = [0u8; 64];
+        let off_subgroup = (1u8..)
+            .find_map(|c0| {
+                bytes[63] = c0;
+                G2Affine::from_compressed_unchecked(&bytes).ok()
+            })
+            .unwrap();
+        assert_eq!(curve.pairing_checked(&p, &off_subgroup), Err(PointError::NotInSubgroup));
+
+        // So is a G1 point off the curve
+        let mut off_curve = p.clone();
+        off_curve.y = off_curve.y.clone() + off_curve.y.clone();
+        assert_eq!(curve.pairing_checked(&off_curve, &q), Err(PointError::NotOnCurve));
+    }
+
+    #[test]
+    fn test_ecpairing_input_parse() {
+        let curve = BN254::new();
+        let hex = |s: &str| -> Vec<u8> {
+            (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+        };
+
+        // G1 generator (1, 2) and the G2 generator in precompile order
+        let g1 = hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+        ));
+        let neg_g1 = hex(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45",
+        ));
+        let g2 = hex(concat!(
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        ));
+
+        // e(G1, G2) · e(-G1, G2) = 1
+        let input = [g1.clone(), g2.clone(), neg_g1, g2.clone()].concat();
+        let pairs = ecpairing_input_parse(&input).unwrap();
+        assert_eq!(pairs[0], (curve.g1_generator(), curve.g2_generator()));
+        assert_eq!(pairs[1].0, -curve.g1_generator());
+        assert!(curve.pairing_check(&pairs));
+
+        let input = [g1.clone(), g2.clone(), g1.clone(), g2.clone()].concat();
+        assert!(!curve.pairing_check(&ecpairing_input_parse(&input).unwrap()));
+
+        // Empty input and all-zero pairs are the identity
+        assert!(curve.pairing_check(&ecpairing_input_parse(&[]).unwrap()));
+        let zeros = ecpairing_input_parse(&[0u8; ECPAIRING_PAIR_BYTES]).unwrap();
+        assert!(zeros[0].0.infinity && zeros[0].1.infinity);
+
+        assert_eq!(
+            ecpairing_input_parse(&input[..100]),
+            Err(PointError::InvalidLength { expected: 192, actual: 100 })
+        );
+        let mut bad = input.clone();
+        bad[ECPAIRING_PAIR_BYTES + 63] ^= 1;
+        assert_eq!(
+            ecpairing_input_parse(&bad),
+            Err(PointError::InvalidBatchPoint { index: 1, source: Box::new(PointError::NotOnCurve) })
+        );
+    }
+}
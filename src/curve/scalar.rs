@@ -0,0 +1,279 @@
+use crate::arithmetic::field::Fp;
+use crate::arithmetic::traits::Field;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Order `r` of the BN254 groups (the scalar field modulus)
+pub const SCALAR_MODULUS: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+/// `r` as little-endian 64-bit limbs
+const SCALAR_MODULUS_LIMBS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// Number of conditional subtractions needed to bring any 256-bit value below `r`
+/// (`2^256 < 6r`)
+const CT_REDUCTION_ROUNDS: usize = 5;
+
+lazy_static! {
+    static ref SCALAR_FIELD_MODULUS: BigUint = BigUint::from_str(SCALAR_MODULUS).unwrap();
+}
+
+/// An element of the BN254 scalar field, i.e. an integer modulo the group order `r`
+///
+/// Arithmetic is closed over scalars and is a separate type from base-field
+/// elements, so the two can't be mixed by accident:
+///
+/// ```compile_fail
+/// use zerosync::{arithmetic::field::Fp, Scalar};
+/// let _ = Scalar::new(1u32.into()) + Fp::from_u64(1, Scalar::modulus());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scalar(Fp);
+
+impl Scalar {
+    /// Creates a new scalar, reducing `value` modulo `r`
+    pub fn new(value: BigUint) -> Self {
+        Self(Fp::new(value, Self::modulus()))
+    }
+
+    /// Returns the scalar field modulus `r`
+    pub fn modulus() -> BigUint {
+        SCALAR_FIELD_MODULUS.clone()
+    }
+
+    /// Returns the canonical integer representative in `[0, r)`
+    pub fn to_biguint(&self) -> BigUint {
+        self.0.from_montgomery()
+    }
+
+    /// Reduces a field element (typically a base-field coordinate or hash output) modulo `r`
+    pub fn from_base(x: &Fp) -> Self {
+        Self::new(x.from_montgomery())
+    }
+
+    /// Returns the underlying field element (with modulus `r`)
+    pub fn as_fp(&self) -> &Fp {
+        &self.0
+    }
+
+    /// Returns the multiplicative inverse modulo `r`, or `None` for zero
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Self)
+    }
+
+    /// Hashes `domain || msg` to a uniformly distributed scalar
+    ///
+    /// Two Keccak-256 outputs, over `domain || msg || 0` and `domain || msg || 1`,
+    /// form a 512-bit value that is reduced modulo `r`, so the bias is below
+    /// `2^-250`. The inputs are concatenated without length prefixes, so `domain`
+    /// should be a fixed-length tag. `Transcript` challenges are expanded the same
+    /// way from their 32-byte seed and an 8-byte counter.
+    pub fn from_hash(domain: &[u8], msg: &[u8]) -> Scalar {
+        let mut wide = [0u8; 64];
+        for (half, chunk) in wide.chunks_exact_mut(32).enumerate() {
+            let mut hasher = Keccak256::new();
+            hasher.update(domain);
+            hasher.update(msg);
+            hasher.update([half as u8]);
+            chunk.copy_from_slice(&hasher.finalize());
+        }
+        Self::new(BigUint::from_bytes_be(&wide))
+    }
+
+    /// Decodes 32 big-endian bytes (the EVM calldata encoding) and reduces them modulo `r`
+    ///
+    /// The reduction is a fixed number of branch-free conditional subtractions of `r`,
+    /// and the reduced limbs enter Montgomery form through one fixed-time
+    /// multiplication by `R²`, so the running time does not depend on the input value.
+    /// This is the entry point for scalars that come from untrusted calldata.
+    pub fn from_bytes_reduce_ct(bytes: &[u8; 32]) -> Scalar {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 32 - (i + 1) * 8;
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[start..start + 8]);
+            *limb = u64::from_be_bytes(word);
+        }
+
+        for _ in 0..CT_REDUCTION_ROUNDS {
+            ct_sub_modulus_if_geq(&mut limbs);
+        }
+
+        Self(Fp::from_canonical_limbs_ct(&limbs, &SCALAR_FIELD_MODULUS))
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+/// Subtracts `r` from `limbs` if `limbs >= r`, without branching on the value
+fn ct_sub_modulus_if_geq(limbs: &mut [u64; 4]) {
+    let mut diff = [0u64; 4];
+    let mut borrow = 0u64;
+    for i in 0..4 {
+        let (d1, b1) = limbs[i].overflowing_sub(SCALAR_MODULUS_LIMBS[i]);
+        let (d2, b2) = d1.overflowing_sub(borrow);
+        diff[i] = d2;
+        borrow = (b1 | b2) as u64;
+    }
+
+    // All ones when there was no borrow (limbs >= r), zero otherwise
+    let mask = borrow.wrapping_sub(1);
+    for i in 0..4 {
+        limbs[i] = (diff[i] & mask) | (limbs[i] & !mask);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::bn254::BN254;
+
+    fn to_be_bytes32(value: &BigUint) -> [u8; 32] {
+        let bytes = value.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
+    }
+
+    #[test]
+    fn test_modulus_limbs_match_constant() {
+        let limbs = BigUint::from_slice(
+            &SCALAR_MODULUS_LIMBS
+                .iter()
+                .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+                .collect::<Vec<u32>>(),
+        );
+        assert_eq!(limbs, Scalar::modulus());
+    }
+
+    #[test]
+    fn test_base_field_conversions() {
+        let base_modulus = BN254::new().modulus;
+
+        let x = Fp::new(BigUint::from(123456789u64), base_modulus.clone());
+        let scalar = Scalar::from_base(&x);
+        assert_eq!(scalar.to_biguint(), BigUint::from(123456789u64));
+        assert_eq!(Fp::from_scalar(&scalar), x);
+
+        // Base-field values at or above r are reduced
+        let large = Fp::new(&base_modulus - 1u32, base_modulus.clone());
+        assert_eq!(Scalar::from_base(&large).to_biguint(), (&base_modulus - 1u32) % Scalar::modulus());
+
+        // Scalars always fit in the base field
+        let max = Scalar::new(Scalar::modulus() - 1u32);
+        assert_eq!(Fp::from_scalar(&max).from_montgomery(), Scalar::modulus() - 1u32);
+        assert_eq!(Fp::from_scalar(&max).modulus(), base_modulus);
+    }
+
+    #[test]
+    fn test_from_hash_is_reduced_and_reproducible() {
+        let r = Scalar::modulus();
+        for i in 0u32..50 {
+            let msg = i.to_be_bytes();
+            let scalar = Scalar::from_hash(b"zerosync-test", &msg);
+            assert!(scalar.to_biguint() < r);
+            assert_eq!(scalar, Scalar::from_hash(b"zerosync-test", &msg));
+        }
+
+        // The domain tag separates otherwise identical messages
+        assert_ne!(Scalar::from_hash(b"domain-a", b"msg"), Scalar::from_hash(b"domain-b", b"msg"));
+    }
+
+    #[test]
+    fn test_from_bytes_reduce_ct_below_r_unchanged() {
+        let r = Scalar::modulus();
+        let values = [
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(123456789u64),
+            &r >> 1,
+            &r - 1u32,
+        ];
+
+        for value in values.iter() {
+            let scalar = Scalar::from_bytes_reduce_ct(&to_be_bytes32(value));
+            assert_eq!(scalar.to_biguint(), *value);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_reduce_ct_matches_biguint_reference() {
+        let r = Scalar::modulus();
+        let values = [
+            r.clone(),
+            &r + 1u32,
+            &r + 987654321u64,
+            &r + (&r >> 1),
+            (&r << 1) - 1u32,
+            // Largest 256-bit input needs every reduction round
+            (BigUint::from(1u32) << 256) - 1u32,
+        ];
+
+        for value in values.iter() {
+            let scalar = Scalar::from_bytes_reduce_ct(&to_be_bytes32(value));
+            assert_eq!(scalar.to_biguint(), value % &r);
+            assert_eq!(scalar, Scalar::new(value.clone()));
+        }
+    }
+
+    #[test]
+    fn test_scalar_arithmetic_matches_biguint() {
+        let r = Scalar::modulus();
+        let a_int = &r - 12345u32;
+        let b_int: BigUint = BigUint::from(0xdead_beef_cafe_babeu64) << 100usize;
+        let a = Scalar::new(a_int.clone());
+        let b = Scalar::new(b_int.clone());
+
+        assert_eq!((a.clone() + b.clone()).to_biguint(), (&a_int + &b_int) % &r);
+        assert_eq!((a.clone() - b.clone()).to_biguint(), (&a_int + &r - &b_int) % &r);
+        assert_eq!((a.clone() * b.clone()).to_biguint(), (&a_int * &b_int) % &r);
+        assert_eq!((-a.clone()).to_biguint(), &r - &a_int);
+
+        let a_inv = a.inverse().unwrap();
+        assert_eq!((a_inv * a.clone()).to_biguint(), BigUint::from(1u32));
+        assert_eq!(Scalar::new(BigUint::from(0u32)).inverse(), None);
+
+        // Results stay in the scalar field, not the base field
+        let sum = a + b;
+        assert_eq!(sum.as_fp().modulus(), r);
+        assert_ne!(sum.as_fp().modulus(), BN254::new().modulus);
+    }
+}
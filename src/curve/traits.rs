@@ -0,0 +1,125 @@
+use crate::curve::bn254::{BN254, G1Affine, G2Affine};
+use crate::curve::scalar::Scalar;
+use num_bigint::BigUint;
+#[cfg(feature = "prover")]
+use rand::Rng;
+use std::fmt::Debug;
+use std::ops::{Add, Neg};
+
+/// Trait for prime-order curve groups, so protocol code can be written once for G1 and G2
+pub trait CurveGroup:
+    Sized
+    + Clone
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Returns the identity element (point at infinity)
+    fn identity() -> Self;
+
+    /// Returns the standard generator of the group
+    fn generator() -> Self;
+
+    /// Returns true if this is the identity element
+    fn is_identity(&self) -> bool;
+
+    /// Doubles this element
+    fn double(&self) -> Self;
+
+    /// Multiplies this element by an arbitrary-size scalar
+    fn mul_scalar(&self, scalar: &BigUint) -> Self;
+
+    /// Returns a uniformly random multiple of the generator
+    #[cfg(feature = "prover")]
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill(&mut bytes[..]);
+        let scalar = BigUint::from_bytes_le(&bytes) % Scalar::modulus();
+        Self::generator().mul_scalar(&scalar)
+    }
+}
+
+impl CurveGroup for G1Affine {
+    fn identity() -> Self {
+        G1Affine::identity(&BN254::new().modulus)
+    }
+
+    fn generator() -> Self {
+        BN254::new().g1_generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    fn double(&self) -> Self {
+        G1Affine::double(self)
+    }
+
+    fn mul_scalar(&self, scalar: &BigUint) -> Self {
+        // G1 has prime order r, so reducing the scalar first does not change the result
+        G1Affine::mul_scalar(self, &Scalar::new(scalar.clone()))
+    }
+}
+
+impl CurveGroup for G2Affine {
+    fn identity() -> Self {
+        G2Affine::identity(&BN254::new().modulus)
+    }
+
+    fn generator() -> Self {
+        BN254::new().g2_generator()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    fn double(&self) -> Self {
+        G2Affine::double(self)
+    }
+
+    fn mul_scalar(&self, scalar: &BigUint) -> Self {
+        self.windowed_mul(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_group_laws<G: CurveGroup>() {
+        let g = G::generator();
+        assert!(!g.is_identity());
+        assert!(G::identity().is_identity());
+
+        // g + g == g.double() == 2 * g
+        let sum = g.clone() + g.clone();
+        assert_eq!(sum, g.double());
+        assert_eq!(g.mul_scalar(&BigUint::from(2u32)), sum);
+
+        // Identity is neutral and g + (-g) is the identity
+        assert_eq!(g.clone() + G::identity(), g);
+        assert!((g.clone() + (-g.clone())).is_identity());
+
+        // Random elements are distinct multiples of the generator
+        #[cfg(feature = "prover")]
+        {
+            let mut rng = rand::thread_rng();
+            let r1 = G::random(&mut rng);
+            let r2 = G::random(&mut rng);
+            assert_ne!(r1, r2);
+        }
+    }
+
+    #[test]
+    fn test_g1_group_laws() {
+        check_group_laws::<G1Affine>();
+    }
+
+    #[test]
+    fn test_g2_group_laws() {
+        check_group_laws::<G2Affine>();
+    }
+}
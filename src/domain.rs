@@ -0,0 +1,621 @@
+//! Multiplicative evaluation domains over NTT-friendly prime fields
+//!
+//! `EvaluationDomain::new` works over the BN254 scalar field; `new_for_field` takes
+//! any odd prime modulus whose multiplicative group has a large enough 2-power part.
+//!
+//! A domain of size `n` (a power of two) is the subgroup `{1, ω, ..., ω^(n-1)}`
+//! generated by a primitive `n`-th root of unity `ω`.
+
+use crate::arithmetic::field::Fp;
+use crate::arithmetic::traits::Field;
+use crate::curve::scalar::Scalar;
+use crate::polynomial::Polynomial;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::rc::Rc;
+use thiserror::Error;
+
+/// Errors returned when constructing evaluation domains
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DomainError {
+    /// The size is zero or not a power of two
+    #[error("domain size {0} is not a power of two")]
+    InvalidSize(usize),
+    /// The field has no subgroup of the requested size
+    #[error("domain size {size} exceeds the largest supported size {max}")]
+    TooLarge { size: usize, max: usize },
+    /// The modulus is not an odd prime
+    #[error("modulus {0} is not an odd prime")]
+    InvalidModulus(BigUint),
+    /// A polynomial (or product) has more coefficients than the domain has points
+    #[error("polynomial of degree {degree} does not fit a domain of size {size}")]
+    DegreeTooLarge { degree: usize, size: usize },
+}
+
+/// A multiplicative subgroup of power-of-two size in a prime field, by default the
+/// BN254 scalar field
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvaluationDomain {
+    /// Number of elements in the domain
+    size: usize,
+    /// Primitive `size`-th root of unity
+    generator: Fp,
+    /// `size⁻¹`, used by the Lagrange basis
+    size_inv: Fp,
+    /// Shift `g` with `gH` disjoint from `H`, or `None` when `H` is the whole group
+    coset_generator: Option<Fp>,
+}
+
+impl EvaluationDomain {
+    /// Creates the domain of the given power-of-two size over the BN254 scalar field
+    pub fn new(size: usize) -> Result<Self, DomainError> {
+        Self::new_for_field(Scalar::modulus(), size)
+    }
+
+    /// Creates the domain of the given power-of-two size over the prime field `modulus`
+    ///
+    /// The largest supported size is the 2-power part of `modulus - 1`, which is only
+    /// 2 for the BN254 base field. `modulus` must be an odd prime.
+    pub fn new_for_field(modulus: BigUint, size: usize) -> Result<Self, DomainError> {
+        if !size.is_power_of_two() {
+            return Err(DomainError::InvalidSize(size));
+        }
+        if !is_odd_prime(&modulus) {
+            return Err(DomainError::InvalidModulus(modulus));
+        }
+
+        let non_residue = quadratic_non_residue(&modulus);
+        let (two_adicity, root) = two_adic_root_of_unity(&non_residue);
+        let log_size = size.trailing_zeros();
+        if log_size > two_adicity {
+            return Err(DomainError::TooLarge {
+                size,
+                max: 1usize.checked_shl(two_adicity).unwrap_or(usize::MAX),
+            });
+        }
+
+        // Square the 2^s-th root down to a primitive 2^k-th root
+        let mut generator = root;
+        for _ in log_size..two_adicity {
+            generator = Field::square(&generator);
+        }
+        let size_inv = Fp::from_u64(size as u64, modulus.clone())
+            .inverse()
+            .expect("domain size is below the modulus");
+
+        // x lies in H exactly when x^n = 1. A non-residue fails that unless n = 2^s and
+        // its order is a power of two, so it is tried first before small integers
+        let one = Fp::from_u64(1, modulus.clone());
+        let coset_generator = std::iter::once(non_residue)
+            .chain((2u64..).map_while(|g| {
+                (BigUint::from(g) < modulus).then(|| Fp::from_u64(g, modulus.clone()))
+            }))
+            .find(|g| g.pow(size as u64) != one);
+
+        Ok(Self { size, generator, size_inv, coset_generator })
+    }
+
+    /// Returns the number of elements in the domain
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the generator `ω`
+    pub fn generator(&self) -> &Fp {
+        &self.generator
+    }
+
+    /// Returns the `i`-th element `ω^i`
+    pub fn element(&self, i: usize) -> Fp {
+        self.generator.pow((i % self.size) as u64)
+    }
+
+    /// Returns all domain elements in order
+    pub fn elements(&self) -> Vec<Fp> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.one();
+        for _ in 0..self.size {
+            elements.push(current.clone());
+            current = current * self.generator.clone();
+        }
+        elements
+    }
+
+    /// Returns the vanishing polynomial `Z_H(X) = X^n - 1`
+    pub fn vanishing_polynomial(&self) -> Polynomial<Fp> {
+        let mut coefficients = vec![self.zero(); self.size + 1];
+        coefficients[0] = -self.one();
+        coefficients[self.size] = self.one();
+        Polynomial::new(coefficients)
+    }
+
+    /// Evaluates `Z_H(z) = z^n - 1`
+    pub fn evaluate_vanishing_polynomial(&self, z: &Fp) -> Fp {
+        z.pow(self.size as u64) - self.one()
+    }
+
+    /// Returns the Lagrange selector `L_i(X)`, which is 1 at `ω^i` and 0 on the rest of the domain
+    ///
+    /// `L_i(X) = ω^i / n · (X^n - 1) / (X - ω^i)`
+    pub fn lagrange_selector(&self, i: usize) -> Polynomial<Fp> {
+        let point = self.element(i);
+        let (quotient, _) = self.vanishing_polynomial().divide_by_linear(&point);
+        let scale = Polynomial::new(vec![point * self.size_inv.clone()]);
+        &quotient * &scale
+    }
+
+    /// Evaluates every Lagrange selector at `z` in O(n) with the barycentric formula
+    ///
+    /// `L_i(z) = ω^i · Z_H(z) / (n · (z - ω^i))`, with a single inversion shared by all
+    /// terms. When `z` is in the domain the result is the Kronecker delta.
+    pub fn lagrange_selectors_at(&self, z: &Fp) -> Vec<Fp> {
+        let elements = self.elements();
+        let vanishing = self.evaluate_vanishing_polynomial(z);
+
+        if vanishing.is_zero() {
+            return elements
+                .iter()
+                .map(|element| if element == z { self.one() } else { self.zero() })
+                .collect();
+        }
+
+        let denominators: Vec<Fp> = elements.iter().map(|element| z.clone() - element.clone()).collect();
+        let inverses = batch_inverse(&denominators);
+        let common = vanishing * self.size_inv.clone();
+
+        elements
+            .into_iter()
+            .zip(inverses)
+            .map(|(element, inverse)| element * common.clone() * inverse)
+            .collect()
+    }
+
+    /// Evaluates `poly` on every domain element with a radix-2 NTT
+    ///
+    /// The result is `[p(1), p(ω), ..., p(ω^(n-1))]`. Coefficients beyond the domain
+    /// size wrap around, i.e. this evaluates `p mod (X^n - 1)`.
+    pub fn fft(&self, poly: &Polynomial<Fp>) -> Vec<Fp> {
+        let mut values = vec![self.zero(); self.size];
+        for (i, coefficient) in poly.coefficients().iter().enumerate() {
+            values[i % self.size] = values[i % self.size].clone() + coefficient.clone();
+        }
+
+        self.butterflies(&mut values, &self.generator);
+        values
+    }
+
+    /// Interpolates the polynomial of degree below `n` from its evaluations on the domain
+    ///
+    /// Inverse of `fft`: the transform with `ω⁻¹` scaled by `n⁻¹`.
+    pub fn ifft(&self, evaluations: &[Fp]) -> Polynomial<Fp> {
+        assert_eq!(evaluations.len(), self.size, "Expected one evaluation per domain element");
+
+        let mut values = evaluations.to_vec();
+        let generator_inv = self.generator.inverse().expect("roots of unity are non-zero");
+        self.butterflies(&mut values, &generator_inv);
+        Polynomial::new(values.into_iter().map(|v| v * self.size_inv.clone()).collect())
+    }
+
+    /// Returns the coset shift `g`, an element outside `H` so `gH` is disjoint from `H`
+    ///
+    /// This is the quadratic non-residue used to find `ω` whenever it lies outside `H`.
+    /// Panics if `H` is the whole multiplicative group, which has no other coset.
+    pub fn coset_generator(&self) -> Fp {
+        self.coset_generator
+            .clone()
+            .expect("the domain is the whole multiplicative group and has no coset")
+    }
+
+    /// Evaluates `poly` on the coset `{g, gω, ..., gω^(n-1)}`
+    ///
+    /// Like `fft`, coefficients beyond the domain size wrap around, which is exact
+    /// for `p(gω^i)` since `(ω^i)^n = 1`.
+    pub fn coset_fft(&self, poly: &Polynomial<Fp>) -> Vec<Fp> {
+        let g = self.coset_generator();
+        let mut shift = self.one();
+        let shifted: Vec<Fp> = poly
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let term = coefficient.clone() * shift.clone();
+                shift = shift.clone() * g.clone();
+                term
+            })
+            .collect();
+        self.fft(&Polynomial::new(shifted))
+    }
+
+    /// Interpolates the polynomial of degree below `n` from its evaluations on the coset
+    pub fn coset_ifft(&self, evaluations: &[Fp]) -> Polynomial<Fp> {
+        let g_inv = self.coset_generator().inverse().expect("the coset generator is non-zero");
+        let mut shift = self.one();
+        let coefficients = self
+            .ifft(evaluations)
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let term = coefficient.clone() * shift.clone();
+                shift = shift.clone() * g_inv.clone();
+                term
+            })
+            .collect();
+        Polynomial::new(coefficients)
+    }
+
+    /// Divides coset evaluations by the vanishing polynomial, giving the quotient's
+    /// coset evaluations
+    ///
+    /// `Z_H(gω^i) = g^n - 1` is the same non-zero constant at every coset point, so
+    /// this costs one inversion and `n` multiplications.
+    pub fn divide_by_vanishing_evals(&self, coset_evals: &[Fp]) -> Vec<Fp> {
+        let vanishing = self.evaluate_vanishing_polynomial(&self.coset_generator());
+        let vanishing_inv = vanishing.inverse().expect("Z_H is non-zero off the domain");
+        coset_evals.iter().map(|eval| eval.clone() * vanishing_inv.clone()).collect()
+    }
+
+    /// In-place radix-2 Cooley-Tukey transform with the primitive `n`-th root `root`
+    fn butterflies(&self, values: &mut [Fp], root: &Fp) {
+        // Bit-reversal permutation; a domain of size one needs none
+        let log_size = self.size.trailing_zeros();
+        if log_size > 0 {
+            for i in 0..self.size {
+                let j = i.reverse_bits() >> (usize::BITS - log_size);
+                if i < j {
+                    values.swap(i, j);
+                }
+            }
+        }
+
+        let mut half = 1;
+        while half < self.size {
+            // Primitive (2·half)-th root of unity
+            let step = root.pow((self.size / (2 * half)) as u64);
+            for chunk in values.chunks_mut(2 * half) {
+                let mut twiddle = self.one();
+                for k in 0..half {
+                    let t = twiddle.clone() * chunk[k + half].clone();
+                    chunk[k + half] = chunk[k].clone() - t.clone();
+                    chunk[k] = chunk[k].clone() + t;
+                    twiddle = twiddle * step.clone();
+                }
+            }
+            half *= 2;
+        }
+    }
+
+    fn zero(&self) -> Fp {
+        Fp::zero_with(self.generator.modulus())
+    }
+
+    fn one(&self) -> Fp {
+        Fp::from_u64(1, self.generator.modulus())
+    }
+}
+
+impl Polynomial<Fp> {
+    /// Multiplies two polynomials by pointwise multiplication over `domain`
+    ///
+    /// Callers that multiply repeatedly can reuse one domain instead of recomputing
+    /// roots of unity. The domain must have at least `deg(self) + deg(other) + 1`
+    /// elements, otherwise the product would wrap around.
+    pub fn mul_fft(&self, other: &Polynomial<Fp>, domain: &EvaluationDomain) -> Result<Polynomial<Fp>, DomainError> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(Polynomial::new(vec![domain.zero()]));
+        }
+
+        let degree = self.degree() + other.degree();
+        if degree >= domain.size() {
+            return Err(DomainError::DegreeTooLarge { degree, size: domain.size() });
+        }
+
+        let lhs = domain.fft(self);
+        let rhs = domain.fft(other);
+        let product: Vec<Fp> = lhs.into_iter().zip(rhs).map(|(a, b)| a * b).collect();
+        Ok(domain.ifft(&product))
+    }
+}
+
+/// A polynomial together with its evaluations over a domain
+///
+/// Multi-round protocols often query the same polynomial at many points. Caching
+/// the evaluations makes in-domain queries O(1) and out-of-domain queries O(n)
+/// without touching the coefficients again. The evaluations are shared, so clones
+/// are cheap.
+#[derive(Debug, Clone)]
+pub struct CachedPolynomial {
+    domain: EvaluationDomain,
+    evaluations: Rc<Vec<Fp>>,
+}
+
+impl CachedPolynomial {
+    /// Evaluates `poly` over `domain` and caches the result
+    ///
+    /// The degree must be below the domain size so the evaluations determine the
+    /// polynomial.
+    pub fn new(poly: &Polynomial<Fp>, domain: EvaluationDomain) -> Result<Self, DomainError> {
+        if !poly.is_zero() && poly.degree() >= domain.size() {
+            return Err(DomainError::DegreeTooLarge { degree: poly.degree(), size: domain.size() });
+        }
+
+        let evaluations = Rc::new(domain.fft(poly));
+        Ok(Self { domain, evaluations })
+    }
+
+    /// Returns the domain the evaluations are cached over
+    pub fn domain(&self) -> &EvaluationDomain {
+        &self.domain
+    }
+
+    /// Returns the cached evaluations in domain order
+    pub fn evaluations(&self) -> &[Fp] {
+        &self.evaluations
+    }
+
+    /// Returns `p(ω^i)`
+    pub fn evaluate_in_domain(&self, i: usize) -> Fp {
+        self.evaluations[i % self.domain.size()].clone()
+    }
+
+    /// Evaluates `p(z)` from the cached values with the barycentric formula
+    ///
+    /// `p(z) = Σ p(ω^i) · L_i(z)`; points inside the domain fall back to a lookup.
+    pub fn evaluate_out_of_domain(&self, z: &Fp) -> Fp {
+        self.domain
+            .lagrange_selectors_at(z)
+            .into_iter()
+            .zip(self.evaluations.iter())
+            .fold(self.domain.zero(), |acc, (selector, value)| acc + selector * value.clone())
+    }
+}
+
+/// Miller-Rabin test with the first twelve prime bases
+///
+/// Deterministic below 3.3 · 10^24 and a strong probable-prime test beyond that.
+fn is_odd_prime(n: &BigUint) -> bool {
+    const BASES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if *n < BigUint::from(3u32) || !n.bit(0) {
+        return false;
+    }
+    let n_minus_one = n - 1u32;
+    let twos = n_minus_one.trailing_zeros().unwrap_or(0);
+    let odd_part = &n_minus_one >> twos;
+
+    BASES.iter().all(|&base| {
+        let base = BigUint::from(base);
+        if base >= *n {
+            return true;
+        }
+        let mut x = base.modpow(&odd_part, n);
+        if x == BigUint::from(1u32) || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..twos {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns the smallest quadratic non-residue modulo the odd prime `modulus`
+fn quadratic_non_residue(modulus: &BigUint) -> Fp {
+    let half = (modulus - 1u32) >> 1;
+    let one = Fp::from_u64(1, modulus.clone());
+    (2u64..)
+        .map(|g| Fp::from_u64(g, modulus.clone()))
+        .find(|g| g.pow_biguint(&half) != one)
+        .expect("odd prime fields have non-residues")
+}
+
+/// Returns the 2-adicity `s` of `p - 1` and a primitive `2^s`-th root of unity
+fn two_adic_root_of_unity(non_residue: &Fp) -> (u32, Fp) {
+    let p_minus_one = non_residue.modulus() - 1u32;
+    let two_adicity = p_minus_one.trailing_zeros().unwrap_or(0) as u32;
+    let odd_part = &p_minus_one >> two_adicity;
+
+    // Any quadratic non-residue raised to the odd part has order exactly 2^s
+    (two_adicity, non_residue.pow_biguint(&odd_part))
+}
+
+/// Inverts every (non-zero) element with a single field inversion
+fn batch_inverse(values: &[Fp]) -> Vec<Fp> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fp::from_u64(1, values[0].modulus());
+    for value in values {
+        prefix.push(acc.clone());
+        acc = acc * value.clone();
+    }
+
+    let mut acc_inv = acc.inverse().expect("values are non-zero");
+    let mut inverses = vec![acc_inv.clone(); values.len()];
+    for i in (0..values.len()).rev() {
+        inverses[i] = acc_inv.clone() * prefix[i].clone();
+        acc_inv = acc_inv * values[i].clone();
+    }
+    inverses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn random_scalar() -> Fp {
+        let bytes: Vec<u8> = (0..32).map(|_| rand::thread_rng().gen()).collect();
+        Fp::new(BigUint::from_bytes_le(&bytes), Scalar::modulus())
+    }
+
+    #[test]
+    fn test_domain_construction() {
+        let domain = EvaluationDomain::new(8).unwrap();
+        let one = Fp::from_u64(1, Scalar::modulus());
+
+        assert_eq!(domain.generator().pow(8), one);
+        assert_ne!(domain.generator().pow(4), one);
+        assert_eq!(domain.element(9), domain.element(1));
+
+        assert_eq!(EvaluationDomain::new(0), Err(DomainError::InvalidSize(0)));
+        assert_eq!(EvaluationDomain::new(12), Err(DomainError::InvalidSize(12)));
+        assert_eq!(
+            EvaluationDomain::new(1 << 29),
+            Err(DomainError::TooLarge { size: 1 << 29, max: 1 << 28 })
+        );
+    }
+
+    #[test]
+    fn test_domain_over_other_fields() {
+        // 17 - 1 = 2^4, so 17 has subgroups of every size up to 16
+        let modulus = BigUint::from(17u32);
+        let one = Fp::from_u64(1, modulus.clone());
+        for log_size in 0..=4 {
+            let size = 1 << log_size;
+            let domain = EvaluationDomain::new_for_field(modulus.clone(), size).unwrap();
+            assert_eq!(domain.generator().pow(size as u64), one);
+            if size > 1 {
+                assert_ne!(domain.generator().pow(size as u64 / 2), one);
+            }
+        }
+        assert_eq!(
+            EvaluationDomain::new_for_field(modulus.clone(), 32),
+            Err(DomainError::TooLarge { size: 32, max: 16 })
+        );
+
+        let domain = EvaluationDomain::new_for_field(modulus.clone(), 8).unwrap();
+        let poly = Polynomial::new((1..=6).map(|c| Fp::from_u64(c, modulus.clone())).collect());
+        for (i, value) in domain.fft(&poly).iter().enumerate() {
+            assert_eq!(*value, poly.evaluate(&domain.element(i)));
+        }
+        assert_eq!(domain.ifft(&domain.fft(&poly)), poly);
+
+        // 16 points cover all of F_17^*, leaving no coset to shift to
+        let whole = EvaluationDomain::new_for_field(modulus.clone(), 16).unwrap();
+        assert!(whole.coset_generator.is_none());
+        for size in [1usize, 2, 4, 8] {
+            let domain = EvaluationDomain::new_for_field(modulus.clone(), size).unwrap();
+            assert_ne!(domain.coset_generator().pow(size as u64), one);
+        }
+
+        // The BN254 base field has 2-adicity 1
+        let base = crate::curve::bn254::BN254::new().modulus;
+        assert!(EvaluationDomain::new_for_field(base.clone(), 2).is_ok());
+        assert_eq!(
+            EvaluationDomain::new_for_field(base, 4),
+            Err(DomainError::TooLarge { size: 4, max: 2 })
+        );
+    }
+
+    #[test]
+    fn test_lagrange_selector_is_kronecker_delta() {
+        let domain = EvaluationDomain::new(8).unwrap();
+        let one = Fp::from_u64(1, Scalar::modulus());
+        let zero = Fp::from_u64(0, Scalar::modulus());
+
+        for i in 0..domain.size() {
+            let selector = domain.lagrange_selector(i);
+            assert_eq!(selector.degree(), domain.size() - 1);
+
+            for j in 0..domain.size() {
+                let expected = if i == j { one.clone() } else { zero.clone() };
+                assert_eq!(selector.evaluate(&domain.element(j)), expected);
+            }
+            assert_eq!(domain.lagrange_selectors_at(&domain.element(i))[i], one);
+        }
+    }
+
+    #[test]
+    fn test_cached_polynomial_matches_direct_evaluation() {
+        let domain = EvaluationDomain::new(16).unwrap();
+        let poly = Polynomial::new((0..13).map(|_| random_scalar()).collect());
+        let cached = CachedPolynomial::new(&poly, domain.clone()).unwrap();
+
+        for i in 0..domain.size() {
+            assert_eq!(cached.evaluate_in_domain(i), poly.evaluate(&domain.element(i)));
+        }
+
+        let z = random_scalar();
+        assert_eq!(cached.evaluate_out_of_domain(&z), poly.evaluate(&z));
+        assert_eq!(cached.evaluate_out_of_domain(&domain.element(3)), cached.evaluate_in_domain(3));
+
+        let too_large = Polynomial::new((0..17).map(|_| random_scalar()).collect());
+        assert_eq!(
+            CachedPolynomial::new(&too_large, domain).unwrap_err(),
+            DomainError::DegreeTooLarge { degree: 16, size: 16 }
+        );
+    }
+
+    #[test]
+    fn test_mul_fft_matches_schoolbook() {
+        let domain = EvaluationDomain::new(16).unwrap();
+        let a = Polynomial::new((0..9).map(|_| random_scalar()).collect());
+        let b = Polynomial::new((0..7).map(|_| random_scalar()).collect());
+
+        assert_eq!(a.mul_fft(&b, &domain).unwrap(), &a * &b);
+        assert_eq!(domain.ifft(&domain.fft(&a)), a);
+
+        // deg 8 + deg 8 + 1 = 17 coefficients don't fit in 16 points
+        assert_eq!(
+            a.mul_fft(&a, &domain),
+            Err(DomainError::DegreeTooLarge { degree: 16, size: 16 })
+        );
+    }
+
+    #[test]
+    fn test_new_for_field_rejects_invalid_moduli() {
+        for modulus in [0u32, 1, 2, 9, 15, 21, 561] {
+            assert_eq!(
+                EvaluationDomain::new_for_field(BigUint::from(modulus), 1),
+                Err(DomainError::InvalidModulus(BigUint::from(modulus)))
+            );
+        }
+        let composite = Scalar::modulus() * 3u32;
+        assert_eq!(
+            EvaluationDomain::new_for_field(composite.clone(), 2),
+            Err(DomainError::InvalidModulus(composite))
+        );
+
+        // 7 used to be the shift in every field, which is zero modulo 7
+        let domain = EvaluationDomain::new_for_field(BigUint::from(7u32), 2).unwrap();
+        let g = domain.coset_generator();
+        assert!(!g.is_zero());
+        assert_ne!(g.pow(2), Fp::from_u64(1, BigUint::from(7u32)));
+
+        for modulus in [3u32, 5, 13, 97] {
+            assert!(EvaluationDomain::new_for_field(BigUint::from(modulus), 2).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_evals() {
+        let domain = EvaluationDomain::new(8).unwrap();
+        let quotient = Polynomial::new((0..8).map(|_| random_scalar()).collect());
+        let numerator = &quotient * &domain.vanishing_polynomial();
+
+        // The numerator has degree 15, but its coset values are still exact
+        let coset_evals = domain.coset_fft(&numerator);
+        let g = domain.coset_generator();
+        for (i, eval) in coset_evals.iter().enumerate() {
+            assert_eq!(*eval, numerator.evaluate(&(g.clone() * domain.element(i))));
+        }
+
+        let quotient_evals = domain.divide_by_vanishing_evals(&coset_evals);
+        assert_eq!(domain.coset_ifft(&quotient_evals), quotient);
+    }
+
+    #[test]
+    fn test_lagrange_selectors_at_matches_polynomials() {
+        let domain = EvaluationDomain::new(16).unwrap();
+        let z = random_scalar();
+
+        let values = domain.lagrange_selectors_at(&z);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(*value, domain.lagrange_selector(i).evaluate(&z));
+        }
+
+        // The selectors form a partition of unity
+        let sum = values.into_iter().fold(Fp::zero_with(Scalar::modulus()), |acc, v| acc + v);
+        assert_eq!(sum, Fp::from_u64(1, Scalar::modulus()));
+    }
+}
@@ -251,14 +251,175 @@ impl<F: Field> Field for Fp6<F> {
             return Err(FieldError::DivisionByZero);
         }
         
-        // Using the formula for the inverse of a cubic extension
+        // Using the formula for the inverse of a cubic extension:
+        // (a + bv + cv^2)^(-1) = (t0 + t1 v + t2 v^2) / (a t0 + ξ(c t1 + b t2))
         let a = self.c0;
         let b = self.c1;
         let c = self.c2;
         
-        // Precompute some values
-        let aa = a * a;
-        let bb = b * b;
-        let cc = c * c;
-        let ac = a * c;
-        let ab = a * b
\ No newline at end of file
+        let t0 = a.square() - (b * c).mul_by_nonresidue();
+        let t1 = c.square().mul_by_nonresidue() - a * b;
+        let t2 = b.square() - a * c;
+        
+        let denominator = a * t0 + (c * t1 + b * t2).mul_by_nonresidue();
+        let inv_denominator = denominator.inverse()?;
+        
+        Ok(Self::new(
+            t0 * inv_denominator,
+            t1 * inv_denominator,
+            t2 * inv_denominator,
+        ))
+    }
+    
+    fn sqrt(&self) -> FieldResult<Self> {
+        if self.is_zero() {
+            return Ok(*self);
+        }
+        
+        // Nothing in the pairing needs square roots in Fp6
+        Err(FieldError::OperationError("Square root is not supported in Fp6".into()))
+    }
+}
+
+// Implement arithmetic operations for Fp6
+impl<F: Field> Add for Fp6<F> {
+    type Output = Self;
+    
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.c0 + rhs.c0,
+            self.c1 + rhs.c1,
+            self.c2 + rhs.c2,
+        )
+    }
+}
+
+impl<F: Field> AddAssign for Fp6<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Field> Sub for Fp6<F> {
+    type Output = Self;
+    
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.c0 - rhs.c0,
+            self.c1 - rhs.c1,
+            self.c2 - rhs.c2,
+        )
+    }
+}
+
+impl<F: Field> SubAssign for Fp6<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Field> Mul for Fp6<F> {
+    type Output = Self;
+    
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a0 + a1 v + a2 v^2)(b0 + b1 v + b2 v^2) with v^3 = ξ,
+        // using Karatsuba to trade three multiplications for additions
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (rhs.c0, rhs.c1, rhs.c2);
+        
+        let v0 = a0 * b0;
+        let v1 = a1 * b1;
+        let v2 = a2 * b2;
+        
+        Self::new(
+            v0 + ((a1 + a2) * (b1 + b2) - v1 - v2).mul_by_nonresidue(),
+            (a0 + a1) * (b0 + b1) - v0 - v1 + v2.mul_by_nonresidue(),
+            (a0 + a2) * (b0 + b2) - v0 - v2 + v1,
+        )
+    }
+}
+
+impl<F: Field> MulAssign for Fp6<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Field> Div for Fp6<F> {
+    type Output = Self;
+    
+    fn div(self, rhs: Self) -> Self::Output {
+        let inverse = rhs.inverse().expect("Division by zero");
+        self * inverse
+    }
+}
+
+impl<F: Field> DivAssign for Fp6<F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<F: Field> Neg for Fp6<F> {
+    type Output = Self;
+    
+    fn neg(self) -> Self::Output {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl<F: Field> fmt::Display for Fp6<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} + {}v + {}v^2)", self.c0, self.c1, self.c2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fp::Fp;
+    
+    fn fp6(values: [u64; 6]) -> Fp6<Fp> {
+        let fp2 = |a: u64, b: u64| Fp2::new(Fp::bn254_scalar(a), Fp::bn254_scalar(b));
+        Fp6::new(
+            fp2(values[0], values[1]),
+            fp2(values[2], values[3]),
+            fp2(values[4], values[5]),
+        )
+    }
+    
+    #[test]
+    fn test_fp6_inverse() {
+        let x = fp6([3, 1, 4, 1, 5, 9]);
+        assert!((x * x.inverse().unwrap()).is_one());
+        
+        for _ in 0..10 {
+            let x = Fp6::<Fp>::random();
+            if x.is_zero() {
+                continue;
+            }
+            assert!((x * x.inverse().unwrap()).is_one());
+            assert_eq!(x / x, Fp6::one());
+        }
+        
+        assert!(Fp6::<Fp>::zero().inverse().is_err());
+    }
+    
+    #[test]
+    fn test_fp6_multiplication() {
+        let a = fp6([2, 7, 1, 8, 2, 8]);
+        let b = fp6([1, 4, 1, 4, 2, 1]);
+        let c = fp6([3, 5, 8, 9, 7, 9]);
+        
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!(a * Fp6::one(), a);
+        assert_eq!(a - a, Fp6::zero());
+        assert_eq!(a + (-a), Fp6::zero());
+        
+        // v^3 = ξ
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(v * v * v, Fp6::new(Fp2::one().mul_by_nonresidue(), Fp2::zero(), Fp2::zero()));
+    }
+}
@@ -6,7 +6,7 @@ use num_bigint::{BigInt, BigUint, ToBigInt};
 use num_traits::{One, Zero};
 use std::fmt;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use rand::{Rng, thread_rng};
+use rand::{RngCore, thread_rng};
 
 /// Montgomery representation of a field element
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -137,6 +137,55 @@ impl Fp {
     pub fn bn254_scalar(value: impl Into<BigUint>) -> Self {
         Self::new(value.into(), &BN254_FR_PARAMS)
     }
+    
+    /// Draw `params.bits` random bits, or `None` if they are not below the modulus
+    ///
+    /// Rejecting out-of-range samples instead of reducing them keeps the result
+    /// uniform; each draw succeeds with probability above one half.
+    pub fn from_random_bytes<R: RngCore + ?Sized>(rng: &mut R, params: &'static FpParameters) -> Option<Self> {
+        let bits = params.bits;
+        let mut buf = vec![0u8; (bits + 7) / 8];
+        rng.fill_bytes(&mut buf);
+        buf[0] &= 0xffu8 >> ((8 - bits % 8) % 8);
+        
+        let value = BigUint::from_bytes_be(&buf);
+        if value < params.modulus {
+            Some(Self::new(value, params))
+        } else {
+            None
+        }
+    }
+    
+    /// Raise to a full-width exponent using square-and-multiply
+    pub fn pow_biguint(&self, exp: &BigUint) -> Self {
+        let mut result = Self {
+            value: self.params.r.clone(), // One in Montgomery form
+            params: self.params,
+        };
+        
+        for i in (0..exp.bits()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result *= *self;
+            }
+        }
+        
+        result
+    }
+    
+    /// Compute the inverse via Fermat's little theorem as `self^(p - 2)`
+    ///
+    /// The exponent is public and fixed, so the sequence of squarings and
+    /// multiplications does not depend on the value being inverted, unlike the
+    /// extended Euclidean `inverse`.
+    pub fn inverse_ct(&self) -> FieldResult<Self> {
+        if self.is_zero() {
+            return Err(FieldError::DivisionByZero);
+        }
+        
+        let exp = &self.params.modulus - BigUint::from(2u64);
+        Ok(self.pow_biguint(&exp))
+    }
 }
 
 impl Field for Fp {
@@ -156,15 +205,11 @@ impl Field for Fp {
     
     fn random() -> Self {
         let mut rng = thread_rng();
-        let bits = BN254_FR_PARAMS.bits;
-        let bytes = (bits + 7) / 8;
-        
-        let mut buf = vec![0u8; bytes];
-        rng.fill(&mut buf[..]);
-        
-        // Ensure value is less than modulus
-        let value = BigUint::from_bytes_be(&buf) % &BN254_FR_PARAMS.modulus;
-        Self::new(value, &BN254_FR_PARAMS)
+        loop {
+            if let Some(sample) = Self::from_random_bytes(&mut rng, &BN254_FR_PARAMS) {
+                return sample;
+            }
+        }
     }
     
     fn is_zero(&self) -> bool {
@@ -214,25 +259,66 @@ impl Field for Fp {
     
     fn sqrt(&self) -> FieldResult<Self> {
         // Tonelli-Shanks algorithm for square root
-        // Simplified version for fields where p ≡ 3 (mod 4)
-        // For BN254, we use the property that p ≡ 3 (mod 4)
+        // Works for any odd prime modulus; p ≡ 3 (mod 4) takes the a^((p+1)/4) shortcut
         
         if self.is_zero() {
             return Ok(*self);
         }
         
         let modulus = &self.params.modulus;
-        let exp = (modulus + BigUint::from(1u64)) / BigUint::from(4u64);
-        
-        // a^((p+1)/4) mod p
-        let value = self.get_value().modpow(&exp, modulus);
-        let result = Self::new(value, self.params);
+        let one = BigUint::from(1u64);
+        let p_minus_one = modulus - &one;
         
-        // Verify result
-        if result.square() != *self {
+        // Euler's criterion: a is a square iff a^((p-1)/2) == 1
+        if !self.pow_biguint(&(&p_minus_one >> 1)).is_one() {
             return Err(FieldError::OperationError("No square root exists".into()));
         }
         
+        // Factor p - 1 = q * 2^s with q odd
+        let s = p_minus_one.trailing_zeros().unwrap_or(0);
+        let q = &p_minus_one >> s;
+        
+        if s == 1 {
+            let exp = (modulus + &one) >> 2;
+            return Ok(self.pow_biguint(&exp));
+        }
+        
+        // Find a quadratic non-residue z
+        let minus_one = -Self::from_u64(1, self.params);
+        let mut candidate = 2u64;
+        let z = loop {
+            let z = Self::from_u64(candidate, self.params);
+            if z.pow_biguint(&(&p_minus_one >> 1)) == minus_one {
+                break z;
+            }
+            candidate += 1;
+        };
+        
+        let mut m = s;
+        let mut c = z.pow_biguint(&q);
+        let mut t = self.pow_biguint(&q);
+        let mut result = self.pow_biguint(&((&q + &one) >> 1));
+        
+        while !t.is_one() {
+            // Least i with t^(2^i) == 1; i < m since t has order dividing 2^(m-1)
+            let mut i = 0;
+            let mut t_pow = t;
+            while !t_pow.is_one() {
+                t_pow = t_pow.square();
+                i += 1;
+            }
+            
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+            
+            m = i;
+            c = b.square();
+            t *= c;
+            result *= b;
+        }
+        
         Ok(result)
     }
 }
@@ -411,6 +497,89 @@ mod tests {
         assert_eq!(a * a_inv, one);
     }
     
+    #[test]
+    fn test_pow_biguint_and_inverse_ct() {
+        let a = Fp::bn254_scalar(5u64);
+        assert_eq!(a.pow_biguint(&BigUint::from(3u64)), a.pow(3));
+        assert!(a.pow_biguint(&BigUint::zero()).is_one());
+        
+        // Fermat: a^(r - 1) = 1
+        let r_minus_one = &BN254_FR_PARAMS.modulus - BigUint::from(1u64);
+        assert!(a.pow_biguint(&r_minus_one).is_one());
+        
+        for _ in 0..10 {
+            let x = Fp::random();
+            if x.is_zero() {
+                continue;
+            }
+            assert_eq!(x.inverse_ct().unwrap(), x.inverse().unwrap());
+        }
+        
+        assert!(Fp::zero().inverse_ct().is_err());
+    }
+    
+    #[test]
+    fn test_sqrt_tonelli_shanks() {
+        // 13 - 1 = 3 * 2^2 and 29 - 1 = 7 * 2^2, so neither takes the (p+1)/4 shortcut
+        for p in [13u64, 29] {
+            let params: &'static FpParameters =
+                Box::leak(Box::new(FpParameters::new(BigUint::from(p)).unwrap()));
+            
+            for v in 0..p {
+                let a = Fp::from_u64(v, params);
+                let is_square = (0..p).any(|x| (x * x) % p == v);
+                match a.sqrt() {
+                    Ok(root) => {
+                        assert!(is_square, "{} has no square root mod {}", v, p);
+                        assert_eq!(root.square(), a);
+                    }
+                    Err(_) => assert!(!is_square, "missing square root of {} mod {}", v, p),
+                }
+            }
+        }
+        
+        // The BN254 scalar field has 2-adicity 28
+        let a = Fp::bn254_scalar(12345u64);
+        assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+    }
+    
+    #[test]
+    fn test_random_is_uniform() {
+        use rand::SeedableRng;
+        
+        let modulus = &BN254_FR_PARAMS.modulus;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1744);
+        
+        // Eight equal-width ranges of [0, r); reducing 254 random bits modulo r
+        // would land in the lowest three about twice as often as the rest
+        const BUCKETS: usize = 8;
+        const SAMPLES: usize = 4000;
+        let mut counts = [0usize; BUCKETS];
+        let mut drawn = 0;
+        while drawn < SAMPLES {
+            if let Some(sample) = Fp::from_random_bytes(&mut rng, &BN254_FR_PARAMS) {
+                let value = sample.get_value();
+                assert!(&value < modulus);
+                let bucket = (value * BUCKETS) / modulus;
+                counts[bucket.to_u64_digits().first().copied().unwrap_or(0) as usize] += 1;
+                drawn += 1;
+            }
+        }
+        
+        // Chi-squared with 7 degrees of freedom; 24.3 is the 0.1% critical value
+        let expected = (SAMPLES / BUCKETS) as f64;
+        let chi_squared: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi_squared < 24.3, "counts {:?} give chi-squared {}", counts, chi_squared);
+        
+        // `random` retries until a draw is accepted
+        for _ in 0..100 {
+            assert!(&Fp::random().get_value() < modulus);
+        }
+    }
+    
     #[test]
     fn test_batch_inversion() {
         let a = Fp::bn254_scalar(5u64);
@@ -0,0 +1,148 @@
+//! Groth16 verification helpers
+
+use crate::arithmetic::field::Fp;
+use crate::curve::bn254::{G1Affine, G2Affine, BN254};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+/// Errors returned by the Groth16 helpers
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Groth16Error {
+    /// The number of public inputs does not match the verifying key
+    #[error("verifying key expects {expected} public inputs, got {actual}")]
+    InvalidInputLength { expected: usize, actual: usize },
+}
+
+/// A Groth16 verifying key over BN254
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    /// Public input commitments, `ic[0]` is the constant term
+    pub ic: Vec<G1Affine>,
+}
+
+/// A Groth16 proof `(A, B, C)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+/// Combines the public inputs into `vk_x = ic[0] + sum(inputs[i] * ic[i + 1])`
+pub fn prepare_inputs(vk: &VerifyingKey, inputs: &[Fp]) -> Result<G1Affine, Groth16Error> {
+    if inputs.len() + 1 != vk.ic.len() {
+        return Err(Groth16Error::InvalidInputLength {
+            expected: vk.ic.len().saturating_sub(1),
+            actual: inputs.len(),
+        });
+    }
+
+    let scalars: Vec<BigUint> = inputs.iter().map(|input| input.from_montgomery()).collect();
+    let acc = G1Affine::msm(&vk.ic[1..], &scalars);
+
+    Ok(&vk.ic[0] + &acc)
+}
+
+/// Verifies a proof against the verifying key and public inputs
+///
+/// Checks `e(-A, B) · e(α, β) · e(vk_x, γ) · e(C, δ) = 1` with a single final
+/// exponentiation. The points are assumed to be valid subgroup elements, e.g.
+/// decoded with the checked deserializers.
+pub fn verify(vk: &VerifyingKey, proof: &Proof, inputs: &[Fp]) -> Result<bool, Groth16Error> {
+    let vk_x = prepare_inputs(vk, inputs)?;
+
+    let pairs = [
+        (-proof.a.clone(), proof.b.clone()),
+        (vk.alpha_g1.clone(), vk.beta_g2.clone()),
+        (vk_x, vk.gamma_g2.clone()),
+        (proof.c.clone(), vk.delta_g2.clone()),
+    ];
+    Ok(BN254::new().pairing_check(&pairs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_vk(num_inputs: usize) -> VerifyingKey {
+        let curve = BN254::new();
+        let g1 = curve.g1_generator();
+        let g2 = curve.g2_generator();
+
+        VerifyingKey {
+            alpha_g1: g1.clone(),
+            beta_g2: g2.clone(),
+            gamma_g2: g2.clone(),
+            delta_g2: g2,
+            ic: (0..=num_inputs as u64).map(|i| g1.clone() * (i + 3)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_prepare_inputs_matches_manual_fold() {
+        let curve = BN254::new();
+        let vk = test_vk(3);
+        let inputs: Vec<Fp> = [11u32, 0, 123456]
+            .iter()
+            .map(|&v| Fp::new(BigUint::from(v), curve.modulus.clone()))
+            .collect();
+
+        let expected = inputs
+            .iter()
+            .zip(vk.ic[1..].iter())
+            .fold(vk.ic[0].clone(), |acc, (input, ic)| {
+                acc + ic.windowed_mul(&input.from_montgomery())
+            });
+
+        assert_eq!(prepare_inputs(&vk, &inputs).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_prepare_inputs_length_mismatch() {
+        let curve = BN254::new();
+        let vk = test_vk(2);
+        let inputs = vec![Fp::new(BigUint::from(1u32), curve.modulus.clone())];
+
+        assert_eq!(
+            prepare_inputs(&vk, &inputs),
+            Err(Groth16Error::InvalidInputLength { expected: 2, actual: 1 })
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let curve = BN254::new();
+        let g1 = curve.g1_generator();
+        let g2 = curve.g2_generator();
+        let input = |v: u32| Fp::new(BigUint::from(v), curve.modulus.clone());
+
+        // α = 2·G1, β = 5·G2 and ic[i] = (i + 3)·G1, so vk_x = (3 + 11·4 + 5·5)·G1 = 72·G1
+        let mut vk = test_vk(2);
+        vk.alpha_g1 = g1.clone() * 2;
+        vk.beta_g2 = g2.clone() * 5;
+        let inputs = vec![input(11), input(5)];
+
+        // A·B = 7·13 = 91 = 2·5 + 72 + 9, so C = 9·G1 satisfies the equation
+        let proof = Proof {
+            a: g1.clone() * 7,
+            b: g2.clone() * 13,
+            c: g1.clone() * 9,
+        };
+        assert_eq!(verify(&vk, &proof, &inputs), Ok(true));
+
+        let wrong_inputs = vec![input(11), input(6)];
+        assert_eq!(verify(&vk, &proof, &wrong_inputs), Ok(false));
+
+        let forged = Proof { c: g1 * 10, ..proof.clone() };
+        assert_eq!(verify(&vk, &forged, &inputs), Ok(false));
+
+        assert_eq!(
+            verify(&vk, &proof, &inputs[..1]),
+            Err(Groth16Error::InvalidInputLength { expected: 2, actual: 1 })
+        );
+    }
+}
@@ -7,10 +7,15 @@ pub mod arithmetic;
 pub mod curve;
 pub mod util;
 pub mod polynomial;
+pub mod groth16;
+pub mod transcript;
+#[cfg(feature = "prover")]
+pub mod domain;
 
 // Re-export commonly used types
 pub use arithmetic::traits::{Field, PrimeField};
 pub use curve::bn254::{BN254, G1Affine, G2Affine};
+pub use curve::scalar::Scalar;
 
 /// Feature flags
 #[cfg(feature = "parallel")]
@@ -1,5 +1,39 @@
 use std::ops::{Add, Mul};
+use crate::arithmetic::field::{FieldError, Fp, FIELD_ELEMENT_BYTES};
 use crate::arithmetic::traits::Field;
+use crate::curve::bn254::Fp2;
+use num_bigint::BigUint;
+#[cfg(feature = "prover")]
+use num_traits::Zero;
+#[cfg(feature = "prover")]
+use rand::RngCore;
+use thiserror::Error;
+
+/// Size in bytes of the coefficient count prefix in `Polynomial::to_bytes`
+const LENGTH_PREFIX_BYTES: usize = 8;
+
+/// Errors returned by polynomial operations
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PolyError {
+    /// The divisor is the zero polynomial
+    #[error("division by zero polynomial")]
+    DivisionByZero,
+    /// The divisor's leading coefficient is not one
+    #[error("divisor is not monic")]
+    NotMonic,
+    /// The x and y slices have different lengths
+    #[error("got {xs} x-coordinates but {ys} y-coordinates")]
+    LengthMismatch { xs: usize, ys: usize },
+    /// An x-coordinate repeats an earlier one
+    #[error("x-coordinate {index} is a duplicate")]
+    DuplicateX { index: usize },
+    /// The encoding is shorter or longer than its length prefix says
+    #[error("expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+    /// A coefficient failed to decode
+    #[error("invalid coefficient: {0}")]
+    InvalidCoefficient(#[from] FieldError),
+}
 
 /// Represents a univariate polynomial over a field
 #[derive(Clone, Debug, PartialEq)]
@@ -37,37 +71,182 @@ impl<F: Field> Polynomial<F> {
         &self.coefficients
     }
 
+    /// Iterates over the nonzero terms as `(degree, coefficient)`, lowest degree first
+    ///
+    /// The zero polynomial yields nothing.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &F)> {
+        self.coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| !coeff.is_zero())
+    }
+
     /// Creates a zero polynomial
+    ///
+    /// `F::zero()` has no field to take, so prefer `zero_like` when another
+    /// polynomial over the same field is at hand.
     pub fn zero() -> Self {
         Self::new(vec![F::zero()])
     }
 
+    /// Returns the zero polynomial over this polynomial's field
+    pub fn zero_like(&self) -> Self {
+        Self::new(vec![self.coefficients[0].zero_like()])
+    }
+
     /// Returns true if this is the zero polynomial
     pub fn is_zero(&self) -> bool {
         self.degree() == 0 && self.coefficients[0].is_zero()
     }
 
+    /// Evaluates the polynomial at `x` using Horner's method
+    pub fn evaluate(&self, x: &F) -> F {
+        let mut result = x.zero_like();
+        for coeff in self.coefficients.iter().rev() {
+            result = result.mul_add(x, coeff);
+        }
+        result
+    }
+
     /// Computes the formal derivative of the polynomial
     pub fn derivative(&self) -> Self {
         if self.degree() == 0 {
-            return Self::zero();
+            return self.zero_like();
+        }
+
+        let result = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| coeff.mul_u64(i as u64))
+            .collect();
+        Self::new(result)
+    }
+
+    /// Divides by `divisor`, returning `(quotient, remainder)` with `deg(remainder) < deg(divisor)`
+    pub fn divide_with_remainder(&self, divisor: &Self) -> (Self, Self) {
+        if divisor.is_zero() {
+            panic!("division by zero polynomial");
+        }
+
+        let divisor_degree = divisor.degree();
+        if self.is_zero() || self.degree() < divisor_degree {
+            return (self.zero_like(), self.clone());
         }
 
-        let mut result = Vec::with_capacity(self.degree());
-        for (i, coeff) in self.coefficients.iter().skip(1).enumerate() {
-            let mut term = coeff.clone();
-            // Multiply by the power (i + 1)
-            let mut power = F::one();
-            for _ in 0..i+1 {
-                power = power + F::one();
+        let lead_inv = divisor.coefficients[divisor_degree]
+            .inverse()
+            .expect("leading coefficient is non-zero");
+
+        let mut remainder = self.coefficients[..=self.degree()].to_vec();
+        let mut quotient = vec![self.coefficients[0].zero_like(); self.degree() - divisor_degree + 1];
+
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder[i + divisor_degree].clone() * lead_inv.clone();
+            for (j, d) in divisor.coefficients[..=divisor_degree].iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone() - coeff.clone() * d.clone();
             }
-            term = term * power;
-            result.push(term);
+            quotient[i] = coeff;
         }
-        Self::new(result)
+
+        remainder.truncate(divisor_degree.max(1));
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Divides by a monic `divisor`, returning `(quotient, remainder)`
+    ///
+    /// Equivalent to `divide_with_remainder`, but skips the leading-coefficient
+    /// inversion and multiplications. Suited to divisors like `X^n - 1` and `X - z`.
+    pub fn divide_by_monic(&self, divisor: &Self) -> Result<(Self, Self), PolyError> {
+        if divisor.is_zero() {
+            return Err(PolyError::DivisionByZero);
+        }
+
+        let divisor_degree = divisor.degree();
+        if !divisor.coefficients[divisor_degree].is_one() {
+            return Err(PolyError::NotMonic);
+        }
+        if self.is_zero() || self.degree() < divisor_degree {
+            return Ok((self.zero_like(), self.clone()));
+        }
+
+        let mut remainder = self.coefficients[..=self.degree()].to_vec();
+        let mut quotient = vec![self.coefficients[0].zero_like(); self.degree() - divisor_degree + 1];
+
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder[i + divisor_degree].clone();
+            for (j, d) in divisor.coefficients[..divisor_degree].iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone() - coeff.clone() * d.clone();
+            }
+            // The leading term cancels against the monic leading coefficient
+            remainder[i + divisor_degree] = remainder[i + divisor_degree].clone() - coeff.clone();
+            quotient[i] = coeff;
+        }
+
+        remainder.truncate(divisor_degree.max(1));
+        Ok((Self::new(quotient), Self::new(remainder)))
+    }
+
+    /// Divides by `(X - z)` using synthetic division, returning `(quotient, p(z))`
+    pub fn divide_by_linear(&self, z: &F) -> (Self, F) {
+        if self.degree() == 0 {
+            return (self.zero_like(), self.coefficients[0].clone());
+        }
+
+        let mut quotient = vec![z.zero_like(); self.degree()];
+        let mut carry = z.zero_like();
+        for i in (0..=self.degree()).rev() {
+            carry = carry * z.clone() + self.coefficients[i].clone();
+            if i > 0 {
+                quotient[i - 1] = carry.clone();
+            }
+        }
+
+        (Self::new(quotient), carry)
+    }
+
+    /// Computes the monic greatest common divisor using the Euclidean algorithm
+    ///
+    /// The GCD of two zero polynomials is the zero polynomial.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero() {
+            let (_, remainder) = a.divide_with_remainder(&b);
+            a = b;
+            b = remainder;
+        }
+
+        if a.is_zero() {
+            return a;
+        }
+
+        let lead_inv = a.coefficients[a.degree()]
+            .inverse()
+            .expect("leading coefficient is non-zero");
+        Self::new(a.coefficients.into_iter().map(|c| c * lead_inv.clone()).collect())
+    }
+
+    /// Interpolates the polynomial through `(xs[i], ys[i])` from parallel slices
+    #[cfg(feature = "prover")]
+    pub fn from_evaluations(xs: &[F], ys: &[F]) -> Result<Self, PolyError> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch { xs: xs.len(), ys: ys.len() });
+        }
+        for i in 1..xs.len() {
+            if xs[..i].contains(&xs[i]) {
+                return Err(PolyError::DuplicateX { index: i });
+            }
+        }
+
+        let points: Vec<(F, F)> = xs.iter().cloned().zip(ys.iter().cloned()).collect();
+        Ok(Self::interpolate(&points))
     }
 
     /// Interpolates a polynomial from a set of points
+    #[cfg(feature = "prover")]
     pub fn interpolate(points: &[(F, F)]) -> Self {
         let n = points.len();
         if n == 0 {
@@ -83,7 +262,7 @@ impl<F: Field> Polynomial<F> {
             }
         }
 
-        let mut result = Self::zero();
+        let mut result = Self::new(vec![points[0].1.zero_like()]);
         for i in 0..n {
             let mut term = Self::new(vec![points[i].1.clone()]);
             
@@ -107,12 +286,90 @@ impl<F: Field> Polynomial<F> {
     }
 }
 
-impl<'a, F: Field> Add for &'a Polynomial<F> {
+impl Polynomial<Fp> {
+    /// Encodes the polynomial as a big-endian `u64` coefficient count followed by
+    /// each coefficient's canonical 32 big-endian bytes, lowest degree first
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LENGTH_PREFIX_BYTES + self.coefficients.len() * FIELD_ELEMENT_BYTES);
+        bytes.extend_from_slice(&(self.coefficients.len() as u64).to_be_bytes());
+        for coefficient in &self.coefficients {
+            bytes.extend_from_slice(&coefficient.to_bytes_be());
+        }
+        bytes
+    }
+
+    /// Decodes a polynomial produced by `to_bytes`
+    ///
+    /// Coefficients must be canonical, i.e. below `modulus`.
+    pub fn from_bytes(bytes: &[u8], modulus: &BigUint) -> Result<Self, PolyError> {
+        let prefix: [u8; LENGTH_PREFIX_BYTES] = bytes
+            .get(..LENGTH_PREFIX_BYTES)
+            .and_then(|prefix| prefix.try_into().ok())
+            .ok_or(PolyError::InvalidLength { expected: LENGTH_PREFIX_BYTES, actual: bytes.len() })?;
+
+        let count = u64::from_be_bytes(prefix);
+        let expected = usize::try_from(count)
+            .ok()
+            .and_then(|count| count.checked_mul(FIELD_ELEMENT_BYTES))
+            .and_then(|len| len.checked_add(LENGTH_PREFIX_BYTES))
+            .unwrap_or(usize::MAX);
+        if bytes.len() != expected {
+            return Err(PolyError::InvalidLength { expected, actual: bytes.len() });
+        }
+
+        let coefficients = Fp::batch_from_bytes(&bytes[LENGTH_PREFIX_BYTES..], modulus)?;
+        if coefficients.is_empty() {
+            // Keep the zero polynomial in the requested field
+            return Ok(Self::new(vec![Fp::zero_with(modulus.clone())]));
+        }
+        Ok(Self::new(coefficients))
+    }
+
+    /// Evaluates the polynomial at each Fp2 point using Horner's method
+    ///
+    /// Coefficients stay in the base field, so each step adds the next coefficient
+    /// to the real part only instead of lifting it into Fp2.
+    pub fn evaluate_at_fp2_batch(&self, points: &[Fp2]) -> Vec<Fp2> {
+        let modulus = self.coefficients[0].modulus();
+        points
+            .iter()
+            .map(|point| {
+                let mut result = Fp2::zero(&modulus);
+                for coeff in self.coefficients.iter().rev() {
+                    result = result.mul(point);
+                    result.c0 = result.c0 + coeff.clone();
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// Samples a random polynomial of degree exactly `degree` over `modulus`
+    #[cfg(feature = "prover")]
+    pub fn random<R: RngCore + ?Sized>(degree: usize, modulus: &BigUint, rng: &mut R) -> Self {
+        let mut coefficients: Vec<Fp> = (0..=degree).map(|_| Fp::from_random_bytes(rng, modulus)).collect();
+        while coefficients[degree].is_zero() {
+            coefficients[degree] = Fp::from_random_bytes(rng, modulus);
+        }
+        Self::new(coefficients)
+    }
+
+    /// Samples a random polynomial of degree at most `max_degree` over `modulus`
+    ///
+    /// Unlike `random`, the leading coefficient may be zero, so every polynomial of
+    /// bounded degree is equally likely.
+    #[cfg(feature = "prover")]
+    pub fn random_bounded<R: RngCore + ?Sized>(max_degree: usize, modulus: &BigUint, rng: &mut R) -> Self {
+        Self::new((0..=max_degree).map(|_| Fp::from_random_bytes(rng, modulus)).collect())
+    }
+}
+
+impl<F: Field> Add for &Polynomial<F> {
     type Output = Polynomial<F>;
 
     fn add(self, other: Self) -> Self::Output {
         let max_len = self.coefficients.len().max(other.coefficients.len());
-        let mut result = vec![F::zero(); max_len];
+        let mut result = vec![self.coefficients[0].zero_like(); max_len];
 
         for (i, coeff) in self.coefficients.iter().enumerate() {
             result[i] = coeff.clone();
@@ -126,17 +383,17 @@ impl<'a, F: Field> Add for &'a Polynomial<F> {
     }
 }
 
-impl<'a, F: Field> Mul for &'a Polynomial<F> {
+impl<F: Field> Mul for &Polynomial<F> {
     type Output = Polynomial<F>;
 
     fn mul(self, other: Self) -> Self::Output {
         if self.is_zero() || other.is_zero() {
-            return Polynomial::zero();
+            return self.zero_like();
         }
 
         let n = self.coefficients.len();
         let m = other.coefficients.len();
-        let mut result = vec![F::zero(); n + m - 1];
+        let mut result = vec![self.coefficients[0].zero_like(); n + m - 1];
 
         for i in 0..n {
             for j in 0..m {
@@ -150,10 +407,7 @@ impl<'a, F: Field> Mul for &'a Polynomial<F> {
 }
 
 /// Evaluates a polynomial at a given point using Horner's method
+#[deprecated(note = "use `Polynomial::evaluate` instead")]
 pub fn evaluate_polynomial<F: Field>(poly: &Polynomial<F>, x: &F) -> F {
-    let mut result = F::zero();
-    for coeff in poly.coefficients.iter().rev() {
-        result = result * x.clone() + coeff.clone();
-    }
-    result
+    poly.evaluate(x)
 } 
\ No newline at end of file
@@ -0,0 +1,247 @@
+//! Fiat-Shamir transcript based on Keccak-256
+
+use crate::arithmetic::field::Fp;
+use crate::curve::scalar::Scalar;
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+
+/// Current version of the exported transcript state
+pub const TRANSCRIPT_STATE_VERSION: u8 = 1;
+
+/// Size in bytes of an exported transcript state: version byte plus digest
+const EXPORTED_STATE_BYTES: usize = 1 + 32;
+
+/// Errors returned when importing a transcript state
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TranscriptError {
+    /// The state was exported by an unknown format version
+    #[error("unknown transcript state version {0}")]
+    UnknownVersion(u8),
+    /// The input has the wrong size
+    #[error("expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+}
+
+/// A Fiat-Shamir transcript
+///
+/// The transcript keeps a single 32-byte running digest. Every message is absorbed
+/// as `state = keccak(state || len(label) || label || len(msg) || msg)`, and
+/// challenges are derived from the current state and then fed back into it.
+/// Challenges are elements of the BN254 scalar field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    /// Creates a new transcript bound to a protocol label
+    pub fn new(label: &[u8]) -> Self {
+        let mut hasher = Keccak256::new();
+        hasher.update(b"zerosync-transcript");
+        absorb_labeled(&mut hasher, label);
+
+        Self {
+            state: hasher.finalize().into(),
+        }
+    }
+
+    /// Serializes the transcript so a protocol can continue it in a later call
+    ///
+    /// The encoding is a version byte followed by the 32-byte running digest, which
+    /// is all the state the transcript carries.
+    pub fn export_state(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(EXPORTED_STATE_BYTES);
+        bytes.push(TRANSCRIPT_STATE_VERSION);
+        bytes.extend_from_slice(&self.state);
+        bytes
+    }
+
+    /// Restores a transcript produced by `export_state`
+    pub fn import_state(bytes: &[u8]) -> Result<Self, TranscriptError> {
+        if bytes.len() != EXPORTED_STATE_BYTES {
+            return Err(TranscriptError::InvalidLength {
+                expected: EXPORTED_STATE_BYTES,
+                actual: bytes.len(),
+            });
+        }
+        if bytes[0] != TRANSCRIPT_STATE_VERSION {
+            return Err(TranscriptError::UnknownVersion(bytes[0]));
+        }
+
+        let mut state = [0u8; 32];
+        state.copy_from_slice(&bytes[1..]);
+        Ok(Self { state })
+    }
+
+    /// Absorbs a labeled message into the transcript
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state);
+        absorb_labeled(&mut hasher, label);
+        absorb_labeled(&mut hasher, message);
+
+        self.state = hasher.finalize().into();
+    }
+
+    /// Absorbs a labeled vector of scalars
+    ///
+    /// The element count is absorbed before the 32-byte big-endian encodings, so
+    /// `[a, b]` cannot collide with `[a]` followed by `[b]`.
+    pub fn append_scalars(&mut self, label: &[u8], xs: &[Fp]) {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state);
+        absorb_labeled(&mut hasher, label);
+        hasher.update((xs.len() as u64).to_be_bytes());
+        for x in xs {
+            hasher.update(x.to_bytes_be());
+        }
+
+        self.state = hasher.finalize().into();
+    }
+
+    /// Draws a single challenge scalar
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fp {
+        self.challenge_scalars(label, 1).remove(0)
+    }
+
+    /// Draws `n` independent challenge scalars from the current transcript state
+    ///
+    /// A single seed is derived from the state, then each challenge is expanded from
+    /// that seed and its index, so no reabsorption is needed between draws. The
+    /// transcript state advances once per call.
+    pub fn challenge_scalars(&mut self, label: &[u8], n: usize) -> Vec<Fp> {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state);
+        hasher.update(b"challenge");
+        absorb_labeled(&mut hasher, label);
+        hasher.update((n as u64).to_be_bytes());
+        let seed: [u8; 32] = hasher.finalize().into();
+
+        let challenges = (0..n as u64)
+            .map(|counter| Scalar::from_hash(&seed, &counter.to_be_bytes()).as_fp().clone())
+            .collect();
+
+        self.state = seed;
+        challenges
+    }
+}
+
+/// Absorbs a length-prefixed byte string
+fn absorb_labeled(hasher: &mut Keccak256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_be_bytes());
+    hasher.update(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+
+    fn test_transcript() -> Transcript {
+        let mut transcript = Transcript::new(b"test-protocol");
+        transcript.append_message(b"commitment", b"some commitment bytes");
+        transcript
+    }
+
+    #[test]
+    fn test_challenge_matches_scalar_from_hash() {
+        let transcript = test_transcript();
+
+        // Seed derivation of a single-challenge draw
+        let mut hasher = Keccak256::new();
+        hasher.update(transcript.state);
+        hasher.update(b"challenge");
+        absorb_labeled(&mut hasher, b"alpha");
+        hasher.update(1u64.to_be_bytes());
+        let seed: [u8; 32] = hasher.finalize().into();
+
+        let expected = Scalar::from_hash(&seed, &0u64.to_be_bytes());
+        assert_eq!(transcript.clone().challenge_scalar(b"alpha"), *expected.as_fp());
+    }
+
+    #[test]
+    fn test_challenge_scalars_pinned() {
+        let challenges = test_transcript().challenge_scalars(b"batch", 3);
+        let values: Vec<String> = challenges
+            .iter()
+            .map(|c| c.from_montgomery().to_str_radix(16))
+            .collect();
+
+        assert_eq!(
+            values,
+            vec![
+                "225699da14a06f631cf4668da709cb36bcf6c29467c4fc12ca529a2a52704e38",
+                "1fd5c7a953a2f44cd31b39659f26d036f4203d8a9f6af6bc232d33276a4cbd0",
+                "2a273a95c96a4ae28aaca62d3598d9316681fa6fb32f86036f8aa76440b35bce",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_challenge_scalars_distinct_and_deterministic() {
+        let a = test_transcript().challenge_scalars(b"batch", 8);
+        let b = test_transcript().challenge_scalars(b"batch", 8);
+        assert_eq!(a, b);
+
+        for i in 0..a.len() {
+            assert!(a[i].from_montgomery() < Scalar::modulus());
+            for j in (i + 1)..a.len() {
+                assert_ne!(a[i], a[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_scalars_is_length_prefixed() {
+        let a = Fp::new(BigUint::from(1u32), Scalar::modulus());
+        let b = Fp::new(BigUint::from(2u32), Scalar::modulus());
+
+        let mut together = test_transcript();
+        together.append_scalars(b"inputs", &[a.clone(), b.clone()]);
+
+        let mut separate = test_transcript();
+        separate.append_scalars(b"inputs", std::slice::from_ref(&a));
+        separate.append_scalars(b"inputs", std::slice::from_ref(&b));
+
+        assert_ne!(together, separate);
+
+        let mut again = test_transcript();
+        again.append_scalars(b"inputs", &[a, b]);
+        assert_eq!(together, again);
+    }
+
+    #[test]
+    fn test_challenges_depend_on_transcript() {
+        let mut t1 = test_transcript();
+        let mut t2 = test_transcript();
+        t2.append_message(b"extra", b"data");
+        assert_ne!(t1.challenge_scalar(b"c"), t2.challenge_scalar(b"c"));
+
+        // Drawing advances the state
+        let first = t1.challenge_scalar(b"c");
+        let second = t1.challenge_scalar(b"c");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_export_import_continues_protocol() {
+        let mut original = test_transcript();
+        original.challenge_scalar(b"round-1");
+        let exported = original.export_state();
+
+        let mut restored = Transcript::import_state(&exported).unwrap();
+        assert_eq!(restored, original);
+
+        original.append_message(b"round-2", b"prover message");
+        restored.append_message(b"round-2", b"prover message");
+        assert_eq!(restored.challenge_scalars(b"c", 2), original.challenge_scalars(b"c", 2));
+
+        assert_eq!(
+            Transcript::import_state(&exported[..32]),
+            Err(TranscriptError::InvalidLength { expected: 33, actual: 32 })
+        );
+        let mut wrong_version = exported.clone();
+        wrong_version[0] = 2;
+        assert_eq!(Transcript::import_state(&wrong_version), Err(TranscriptError::UnknownVersion(2)));
+    }
+}
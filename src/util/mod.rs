@@ -1,4 +1,4 @@
-/// Utility functions for the ZeroSync library
+//! Utility functions for the ZeroSync library
 
 /// Converts a byte slice to a fixed-size array
 pub fn to_fixed_bytes<const N: usize>(bytes: &[u8]) -> [u8; N] {
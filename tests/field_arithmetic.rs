@@ -32,7 +32,7 @@ proptest! {
         prop_assert_eq!((a.clone() + b.clone()) + c.clone(), a.clone() + (b.clone() + c.clone()));
 
         // Identity: a + 0 = a
-        let zero = Fp::zero();
+        let zero = a.zero_like();
         prop_assert_eq!(a.clone() + zero.clone(), a.clone());
 
         // Inverse: a + (-a) = 0
@@ -53,7 +53,7 @@ proptest! {
         prop_assert_eq!((a.clone() * b.clone()) * c.clone(), a.clone() * (b.clone() * c.clone()));
 
         // Identity: a * 1 = a
-        let one = Fp::one();
+        let one = a.one_like();
         prop_assert_eq!(a.clone() * one.clone(), a.clone());
 
         // Distributivity: a * (b + c) = (a * b) + (a * c)
@@ -70,7 +70,7 @@ proptest! {
         if !a.is_zero() {
             // a * a^(-1) = 1
             let inv_a = a.inverse().unwrap();
-            let one = Fp::one();
+            let one = a.one_like();
             prop_assert_eq!(a.clone() * inv_a.clone(), one.clone());
             prop_assert_eq!(inv_a.clone() * a.clone(), one);
         }
@@ -105,7 +105,7 @@ proptest! {
         prop_assert_eq!(a.clone() - b.clone(), a.clone() + neg_b);
 
         // a - a = 0
-        let zero = Fp::zero();
+        let zero = a.zero_like();
         prop_assert_eq!(a.clone() - a.clone(), zero);
     }
 }
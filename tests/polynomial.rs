@@ -1,7 +1,10 @@
 use num_bigint::BigUint;
-use num_traits::Num;
-use zerosync::arithmetic::field::Fp;
-use zerosync::polynomial::{Polynomial, evaluate_polynomial};
+use num_traits::{Num, Zero};
+use zerosync::arithmetic::field::{FieldError, Fp};
+use zerosync::curve::bn254::{Fp2, BN254};
+use zerosync::polynomial::{PolyError, Polynomial};
+#[allow(deprecated)]
+use zerosync::polynomial::evaluate_polynomial;
 
 const TEST_MODULUS: &str = "17";  // Small prime for testing
 
@@ -29,6 +32,7 @@ fn test_polynomial_zero() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_polynomial_evaluation() {
     let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
     let poly = Polynomial::new(vec![
@@ -45,6 +49,100 @@ fn test_polynomial_evaluation() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_polynomial_evaluate_method() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let poly = Polynomial::new(vec![
+        Fp::new(BigUint::from(1u64), modulus.clone()),
+        Fp::new(BigUint::from(2u64), modulus.clone()),
+        Fp::new(BigUint::from(3u64), modulus.clone()),
+    ]);  // 3x^2 + 2x + 1
+    
+    // At x = 2: 17 ≡ 0, at x = 3: 34 ≡ 0, at x = 1: 6 (mod 17)
+    let at = |v: u64| poly.evaluate(&Fp::new(BigUint::from(v), modulus.clone()));
+    assert_eq!(at(2), Fp::new(BigUint::from(0u64), modulus.clone()));
+    assert_eq!(at(3), Fp::new(BigUint::from(0u64), modulus.clone()));
+    assert_eq!(at(1), Fp::new(BigUint::from(6u64), modulus.clone()));
+    
+    // Composition: f(g(3)) with f = x + 1 and g = x + 2
+    let outer = Polynomial::new(vec![
+        Fp::new(BigUint::from(1u64), modulus.clone()),
+        Fp::new(BigUint::from(1u64), modulus.clone()),
+    ]);
+    let inner = Polynomial::new(vec![
+        Fp::new(BigUint::from(2u64), modulus.clone()),
+        Fp::new(BigUint::from(1u64), modulus.clone()),
+    ]);
+    let x = Fp::new(BigUint::from(3u64), modulus.clone());
+    assert_eq!(outer.evaluate(&inner.evaluate(&x)), Fp::new(BigUint::from(6u64), modulus));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_polynomial_evaluate_matches_free_function() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let poly = Polynomial::new(vec![
+        Fp::new(BigUint::from(5u64), modulus.clone()),
+        Fp::new(BigUint::from(0u64), modulus.clone()),
+        Fp::new(BigUint::from(11u64), modulus.clone()),
+        Fp::new(BigUint::from(3u64), modulus.clone()),
+    ]);  // 3x^3 + 11x^2 + 5
+    
+    for v in 0..17u64 {
+        let x = Fp::new(BigUint::from(v), modulus.clone());
+        assert_eq!(poly.evaluate(&x), zerosync::polynomial::evaluate_polynomial(&poly, &x));
+    }
+}
+
+#[test]
+fn test_polynomial_evaluate_at_fp2_batch() {
+    let modulus = BN254::new().modulus;
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+    let poly = Polynomial::new(vec![fp(7), fp(0), fp(5), fp(12), fp(3)]);  // 3x^4 + 12x^3 + 5x^2 + 7
+    
+    let points = vec![
+        Fp2::new(fp(2), fp(9)),
+        Fp2::new(fp(0), fp(1)),
+        Fp2::new(fp(123456789), fp(987654321)),
+        Fp2::zero(&modulus),
+    ];
+    let values = poly.evaluate_at_fp2_batch(&points);
+    assert_eq!(values.len(), points.len());
+    
+    // Reference: sum of c_i * x^i with each power computed in Fp2
+    for (point, value) in points.iter().zip(&values) {
+        let mut expected = Fp2::zero(&modulus);
+        let mut power = Fp2::one(&modulus);
+        for coeff in poly.coefficients() {
+            expected = expected.add(&power.mul_by_fp(coeff));
+            power = power.mul(point);
+        }
+        assert_eq!(*value, expected);
+    }
+    
+    // Real points give the base field evaluation with a zero imaginary part
+    let real_points: Vec<Fp2> = (0..5).map(|v| Fp2::new(fp(v), fp(0))).collect();
+    for (v, value) in poly.evaluate_at_fp2_batch(&real_points).iter().enumerate() {
+        assert_eq!(value.c0, poly.evaluate(&fp(v as u64)));
+        assert!(value.c1.is_zero());
+    }
+    
+    assert!(poly.evaluate_at_fp2_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_polynomial_terms() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+    
+    let poly = Polynomial::new(vec![fp(1), fp(0), fp(3)]);  // 3x^2 + 1
+    let terms: Vec<(usize, Fp)> = poly.terms().map(|(i, c)| (i, c.clone())).collect();
+    assert_eq!(terms, vec![(0, fp(1)), (2, fp(3))]);
+    
+    let zero = Polynomial::new(vec![fp(0)]);
+    assert_eq!(zero.terms().count(), 0);
+}
+
 #[test]
 fn test_polynomial_addition() {
     let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
@@ -159,6 +257,7 @@ fn test_polynomial_scalar_multiplication() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_polynomial_composition() {
     let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
     let outer = Polynomial::new(vec![
@@ -198,6 +297,50 @@ fn test_polynomial_derivative() {
 }
 
 #[test]
+fn test_polynomial_derivative_high_degree() {
+    let modulus = BigUint::from_str_radix(
+        "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+        10,
+    ).unwrap();
+    let values: Vec<u64> = (0..=1000u64).map(|i| i * i + 7).collect();
+    let poly = Polynomial::new(
+        values.iter().map(|&v| Fp::new(BigUint::from(v), modulus.clone())).collect()
+    );
+    
+    let start = std::time::Instant::now();
+    let derivative = poly.derivative();
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    
+    // Coefficient i of the derivative is (i + 1) * c_{i+1}
+    assert_eq!(derivative.degree(), 999);
+    for (i, coeff) in derivative.coefficients().iter().enumerate() {
+        let expected = BigUint::from(i as u64 + 1) * BigUint::from(values[i + 1]) % &modulus;
+        assert_eq!(coeff.from_montgomery(), expected);
+    }
+    
+    // Low-degree cases agree with a term-by-term reference built from repeated additions
+    let small_modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    for degree in 0..6usize {
+        let coeffs: Vec<Fp> = (0..=degree)
+            .map(|i| Fp::new(BigUint::from(3 * i as u64 + 1), small_modulus.clone()))
+            .collect();
+        let poly = Polynomial::new(coeffs.clone());
+        
+        let reference: Vec<Fp> = coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| (1..i).fold(c.clone(), |acc, _| acc + c.clone()))
+            .collect();
+        // A constant's derivative is the zero polynomial over the same field
+        let expected = if reference.is_empty() { poly.zero_like() } else { Polynomial::new(reference) };
+        assert_eq!(poly.derivative(), expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "prover")]
+#[allow(deprecated)]
 fn test_polynomial_interpolation() {
     let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
     let points = vec![
@@ -217,6 +360,10 @@ fn test_polynomial_interpolation() {
     
     let poly = Polynomial::interpolate(&points);
     
+    for (x, y) in &points {
+        assert_eq!(poly.evaluate(x), *y);
+    }
+    
     // Verify the polynomial passes through all points
     for (x, y) in points {
         let result = evaluate_polynomial(&poly, &x);
@@ -251,4 +398,189 @@ fn test_polynomial_properties() {
     let left = &p1 * &(&p2 + &p3);
     let right = &(&p1 * &p2) + &(&p1 * &p3);
     assert_eq!(left, right);
-} 
\ No newline at end of file
+} 
+
+#[test]
+fn test_polynomial_gcd() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+    
+    // (X - 1) = X + 16, (X - 2) = X + 15, (X - 3) = X + 14 over F_17
+    let x_minus_1 = Polynomial::new(vec![fp(16), fp(1)]);
+    let x_minus_2 = Polynomial::new(vec![fp(15), fp(1)]);
+    let x_minus_3 = Polynomial::new(vec![fp(14), fp(1)]);
+    
+    let a = &x_minus_1 * &x_minus_2;
+    let b = &x_minus_1 * &x_minus_3;
+    assert_eq!(a.gcd(&b), x_minus_1);
+    
+    // Scaling an input does not change the monic GCD
+    let scaled = &b * &Polynomial::new(vec![fp(5)]);
+    assert_eq!(a.gcd(&scaled), x_minus_1);
+    
+    // gcd(p, 0) is p made monic
+    let p = Polynomial::new(vec![fp(6), fp(3)]);  // 3X + 6 = 3(X + 2)
+    let zero = Polynomial::new(vec![fp(0)]);
+    assert_eq!(p.gcd(&zero), Polynomial::new(vec![fp(2), fp(1)]));
+    assert_eq!(zero.gcd(&p), Polynomial::new(vec![fp(2), fp(1)]));
+}
+
+#[test]
+fn test_polynomial_divide_by_monic_errors() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+    
+    let p = Polynomial::new(vec![fp(1), fp(2), fp(3)]);
+    let zero = Polynomial::new(vec![fp(0)]);
+    let not_monic = Polynomial::new(vec![fp(1), fp(2)]);
+    
+    assert_eq!(p.divide_by_monic(&zero), Err(PolyError::DivisionByZero));
+    assert_eq!(p.divide_by_monic(&not_monic), Err(PolyError::NotMonic));
+}
+
+#[test]
+fn test_polynomial_bytes_round_trip() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+
+    let polys = vec![
+        Polynomial::new(vec![fp(0)]),
+        Polynomial::new(vec![fp(5)]),
+        Polynomial::new(vec![fp(1), fp(0), fp(16)]),
+        Polynomial::new((0..40).map(|i| fp(i % 17)).chain([fp(3)]).collect()),
+    ];
+    for poly in polys {
+        let bytes = poly.to_bytes();
+        assert_eq!(bytes.len(), 8 + 32 * poly.coefficients().len());
+        assert_eq!(Polynomial::from_bytes(&bytes, &modulus).unwrap(), poly);
+    }
+
+    // An empty coefficient list decodes to the zero polynomial of the given field
+    let zero = Polynomial::from_bytes(&[0u8; 8], &modulus).unwrap();
+    assert!(zero.is_zero());
+    assert_eq!(zero.coefficients()[0].modulus(), modulus);
+
+    let bytes = Polynomial::new(vec![fp(1), fp(2)]).to_bytes();
+    assert_eq!(
+        Polynomial::from_bytes(&bytes[..40], &modulus),
+        Err(PolyError::InvalidLength { expected: 72, actual: 40 })
+    );
+    assert_eq!(
+        Polynomial::from_bytes(&bytes[..4], &modulus),
+        Err(PolyError::InvalidLength { expected: 8, actual: 4 })
+    );
+
+    let mut non_canonical = bytes.clone();
+    non_canonical[71] = 17;
+    assert_eq!(
+        Polynomial::from_bytes(&non_canonical, &modulus),
+        Err(PolyError::InvalidCoefficient(FieldError::NonCanonical { index: 1, got_bits: 5 }))
+    );
+}
+
+#[test]
+#[cfg(feature = "prover")]
+fn test_polynomial_random_degree_bounds() {
+    let modulus = BigUint::from(17u32);
+    let mut rng = rand::thread_rng();
+
+    // Small field so zero leading coefficients actually occur
+    let mut saw_lower_degree = false;
+    for _ in 0..500 {
+        let bounded = Polynomial::random_bounded(4, &modulus, &mut rng);
+        assert!(bounded.degree() <= 4);
+        saw_lower_degree |= bounded.degree() < 4;
+        assert!(bounded.coefficients().iter().all(|c| c.modulus() == modulus));
+
+        let exact = Polynomial::random(4, &modulus, &mut rng);
+        assert_eq!(exact.degree(), 4);
+    }
+    assert!(saw_lower_degree);
+}
+
+#[test]
+#[cfg(feature = "prover")]
+fn test_polynomial_from_evaluations() {
+    let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+    let fp = |v: u64| Fp::new(BigUint::from(v), modulus.clone());
+    
+    // 3x^2 + 2x + 1 at x = 0, 1, 2 is 1, 6, 0 (mod 17)
+    let xs = vec![fp(0), fp(1), fp(2)];
+    let ys = vec![fp(1), fp(6), fp(0)];
+    let poly = Polynomial::from_evaluations(&xs, &ys).unwrap();
+    assert_eq!(poly, Polynomial::new(vec![fp(1), fp(2), fp(3)]));
+    
+    assert_eq!(
+        Polynomial::from_evaluations(&xs, &ys[..2]),
+        Err(PolyError::LengthMismatch { xs: 3, ys: 2 })
+    );
+    assert_eq!(
+        Polynomial::from_evaluations(&[fp(1), fp(2), fp(1)], &ys),
+        Err(PolyError::DuplicateX { index: 2 })
+    );
+}
+
+mod division_properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn poly_from(values: &[u64]) -> Polynomial<Fp> {
+        let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+        Polynomial::new(values.iter().map(|&v| Fp::new(BigUint::from(v), modulus.clone())).collect())
+    }
+
+    fn coefficients() -> impl Strategy<Value = Vec<u64>> {
+        prop::collection::vec(0u64..17, 1..=21)
+    }
+
+    proptest! {
+        #[test]
+        fn divide_with_remainder_recombines(dividend in coefficients(), divisor in coefficients()) {
+            let dividend = poly_from(&dividend);
+            let divisor = poly_from(&divisor);
+            prop_assume!(!divisor.is_zero());
+
+            let (quotient, remainder) = dividend.divide_with_remainder(&divisor);
+            prop_assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+            prop_assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn divide_by_linear_recombines(coeffs in coefficients(), z in 0u64..17) {
+            let modulus = BigUint::from_str_radix(TEST_MODULUS, 10).unwrap();
+            let p = poly_from(&coeffs);
+            let z = Fp::new(BigUint::from(z), modulus.clone());
+
+            let (quotient, value) = p.divide_by_linear(&z);
+            prop_assert_eq!(value.clone(), evaluate_polynomial(&p, &z));
+
+            // X - z
+            let linear = Polynomial::new(vec![-z, Fp::new(BigUint::from(1u64), modulus)]);
+            let recombined = &(&quotient * &linear) + &Polynomial::new(vec![value]);
+            prop_assert_eq!(recombined, p);
+        }
+
+        #[test]
+        fn divide_by_monic_matches_general(dividend in coefficients(), divisor in prop::collection::vec(0u64..17, 0..=20)) {
+            let dividend = poly_from(&dividend);
+            let mut divisor = divisor;
+            divisor.push(1);
+            let divisor = poly_from(&divisor);
+
+            let fast = dividend.divide_by_monic(&divisor).unwrap();
+            prop_assert_eq!(fast, dividend.divide_with_remainder(&divisor));
+        }
+
+        #[test]
+        fn product_division_is_exact(p in coefficients(), q in coefficients()) {
+            let p = poly_from(&p);
+            let q = poly_from(&q);
+            prop_assume!(!q.is_zero());
+
+            let (quotient, remainder) = (&p * &q).divide_with_remainder(&q);
+            prop_assert_eq!(quotient, p);
+            prop_assert!(remainder.is_zero());
+        }
+    }
+}